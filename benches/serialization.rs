@@ -0,0 +1,52 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fuel_crypto::SecretKey;
+use fuel_tx::field::{Inputs, Outputs};
+use fuel_tx::*;
+use fuel_types::bytes::{Deserializable, SerializableVec};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const IO_COUNT: usize = 100;
+
+fn script_with_io(rng: &mut StdRng) -> Script {
+    let secret = SecretKey::random(rng);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+
+    for _ in 0..IO_COUNT {
+        builder
+            .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+            .add_output(Output::coin(rng.gen(), rng.gen(), rng.gen()));
+    }
+
+    builder.finalize()
+}
+
+fn serialization(c: &mut Criterion) {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let script = script_with_io(rng);
+    let bytes = script.clone().to_bytes();
+
+    let mut group = c.benchmark_group("script-100-io");
+
+    group.bench_function("encode", |b| {
+        b.iter(|| black_box(script.clone()).to_bytes())
+    });
+
+    group.bench_function("decode", |b| {
+        b.iter(|| Script::from_bytes(black_box(&bytes)).expect("failed to decode script"))
+    });
+
+    group.bench_function("offsets", |b| {
+        b.iter(|| {
+            (0..IO_COUNT).for_each(|i| {
+                black_box(script.inputs_offset_at(i));
+                black_box(script.outputs_offset_at(i));
+            })
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, serialization);
+criterion_main!(benches);
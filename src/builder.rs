@@ -1,12 +1,12 @@
 use crate::transaction::field::{BytecodeLength, BytecodeWitnessIndex, Witnesses};
 use crate::transaction::{field, Chargeable, Create, Executable, Script, Signable};
 use crate::{
-    Cacheable, Checked, ConsensusParameters, Input, IntoChecked, Mint, Output, StorageSlot,
-    Transaction, TxPointer, Witness,
+    Cacheable, CheckError, Checked, ConsensusParameters, Input, IntoChecked, Mint, Output,
+    StorageSlot, Transaction, TxPointer, Witness,
 };
 
 use fuel_crypto::SecretKey;
-use fuel_types::{Salt, Word};
+use fuel_types::{Address, Bytes32, ContractId, Salt, Word};
 
 use alloc::vec::Vec;
 
@@ -224,6 +224,88 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self
     }
 
+    /// Append a coin input owned by `owner` together with its already produced `signature`.
+    ///
+    /// The signature covers the id of the final transaction, so it must be produced only once
+    /// the inputs and outputs of the transaction are settled; adding anything else that changes
+    /// the id afterwards invalidates it.
+    pub fn add_signed_coin_input(
+        &mut self,
+        utxo_id: crate::UtxoId,
+        owner: Address,
+        amount: Word,
+        asset_id: fuel_types::AssetId,
+        tx_pointer: TxPointer,
+        maturity: Word,
+        signature: Witness,
+    ) -> &mut Self {
+        debug_assert!(
+            signature.as_signature().is_some(),
+            "the witness of a signed coin input must be a signature"
+        );
+
+        let witness_index = self.tx.witnesses().len() as u8;
+
+        self.tx.add_witness(signature);
+        self.tx.add_input(Input::coin_signed(
+            utxo_id,
+            owner,
+            amount,
+            asset_id,
+            tx_pointer,
+            witness_index,
+            maturity,
+        ));
+
+        self
+    }
+
+    /// Like [`Self::add_unsigned_coin_input`], but fails with
+    /// [`CheckError::TransactionWitnessesMax`] instead of allocating a witness beyond
+    /// `params.max_witnesses`.
+    #[cfg(feature = "std")]
+    pub fn try_add_unsigned_coin_input(
+        &mut self,
+        secret: SecretKey,
+        utxo_id: crate::UtxoId,
+        amount: Word,
+        asset_id: fuel_types::AssetId,
+        tx_pointer: TxPointer,
+        maturity: Word,
+        params: &ConsensusParameters,
+    ) -> Result<&mut Self, CheckError> {
+        self.check_witness_available(params)?;
+
+        Ok(self.add_unsigned_coin_input(secret, utxo_id, amount, asset_id, tx_pointer, maturity))
+    }
+
+    /// Like [`Self::add_unsigned_message_input`], but fails with
+    /// [`CheckError::TransactionWitnessesMax`] instead of allocating a witness beyond
+    /// `params.max_witnesses`.
+    #[cfg(feature = "std")]
+    pub fn try_add_unsigned_message_input(
+        &mut self,
+        secret: SecretKey,
+        sender: fuel_types::Address,
+        nonce: Word,
+        amount: Word,
+        data: Vec<u8>,
+        params: &ConsensusParameters,
+    ) -> Result<&mut Self, CheckError> {
+        self.check_witness_available(params)?;
+
+        Ok(self.add_unsigned_message_input(secret, sender, nonce, amount, data))
+    }
+
+    #[cfg(feature = "std")]
+    fn check_witness_available(&self, params: &ConsensusParameters) -> Result<(), CheckError> {
+        if self.tx.witnesses().len() >= params.max_witnesses as usize {
+            return Err(CheckError::TransactionWitnessesMax);
+        }
+
+        Ok(())
+    }
+
     pub fn inputs(&self) -> &[Input] {
         self.tx.inputs()
     }
@@ -242,6 +324,33 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self
     }
 
+    /// Append a contract input together with the contract output that spends it.
+    ///
+    /// The output `input_index` points to the appended input.
+    pub fn add_contract(
+        &mut self,
+        utxo_id: crate::UtxoId,
+        balance_root: Bytes32,
+        state_root: Bytes32,
+        tx_pointer: TxPointer,
+        contract_id: ContractId,
+    ) -> &mut Self {
+        let input_index = self.tx.inputs().len() as u8;
+
+        self.tx.add_input(Input::contract(
+            utxo_id,
+            balance_root,
+            state_root,
+            tx_pointer,
+            contract_id,
+        ));
+        self.tx
+            .outputs_mut()
+            .push(Output::contract(input_index, balance_root, state_root));
+
+        self
+    }
+
     pub fn add_witness(&mut self, witness: Witness) -> &mut Self {
         self.tx.add_witness(witness);
 
@@ -280,6 +389,44 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
 
         tx
     }
+
+    /// Finalize the transaction without signing it, keeping the placeholder witnesses of the
+    /// signed inputs.
+    ///
+    /// Returns the transaction together with the `(input_index, witness_index, owner)` of every
+    /// signed input that still has an empty witness, so the signatures can be produced offline
+    /// and inserted afterwards.
+    #[cfg(feature = "std")]
+    pub fn finalize_without_signing(&mut self) -> (Tx, Vec<(usize, u8, Address)>) {
+        let tx = self._finalize_without_signature();
+
+        let unsigned = tx
+            .inputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| match input {
+                Input::CoinSigned {
+                    owner,
+                    witness_index,
+                    ..
+                }
+                | Input::MessageSigned {
+                    recipient: owner,
+                    witness_index,
+                    ..
+                } => Some((index, *witness_index, *owner)),
+                _ => None,
+            })
+            .filter(|(_, witness_index, _)| {
+                tx.witnesses()
+                    .get(*witness_index as usize)
+                    .map(|witness| witness.as_ref().is_empty())
+                    .unwrap_or(true)
+            })
+            .collect();
+
+        (tx, unsigned)
+    }
 }
 
 impl<Tx: field::Outputs> TransactionBuilder<Tx> {
@@ -422,3 +569,170 @@ impl TransactionBuilder<Mint> {
             .expect("failed to check tx")
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::field::{Inputs, Outputs};
+
+    use fuel_crypto::Signature;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn finalize_without_signing_lists_unsigned_inputs() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        let coin_secret = SecretKey::random(rng);
+        let message_secret = SecretKey::random(rng);
+        let signed_owner = rng.gen();
+
+        let (tx, unsigned) = TransactionBuilder::script(vec![], vec![])
+            .add_unsigned_coin_input(coin_secret, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+            .add_input(Input::contract(
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+            ))
+            .add_unsigned_message_input(message_secret, rng.gen(), rng.gen(), rng.gen(), vec![])
+            // Input with an already provided witness
+            .add_input(Input::coin_signed(
+                rng.gen(),
+                signed_owner,
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                2,
+                0,
+            ))
+            .add_witness(vec![0xfa; 64].into())
+            .finalize_without_signing();
+
+        let expected = vec![
+            (0, 0, Input::owner(&coin_secret.public_key())),
+            (2, 1, Input::owner(&message_secret.public_key())),
+        ];
+
+        assert_eq!(expected, unsigned);
+        assert_eq!(4, tx.inputs().len());
+        assert!(tx.witnesses()[..2].iter().all(|w| w.as_ref().is_empty()));
+    }
+
+    #[test]
+    fn try_add_unsigned_input_respects_max_witnesses() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let params = ConsensusParameters::DEFAULT.with_max_witnesses(2);
+
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        for _ in 0..params.max_witnesses {
+            builder
+                .try_add_unsigned_coin_input(
+                    SecretKey::random(rng),
+                    rng.gen(),
+                    rng.gen(),
+                    rng.gen(),
+                    rng.gen(),
+                    0,
+                    &params,
+                )
+                .expect("Witness slot should be available");
+        }
+
+        let err = builder
+            .try_add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                0,
+                &params,
+            )
+            .expect_err("Witnesses limit should be exceeded");
+        assert_eq!(CheckError::TransactionWitnessesMax, err);
+
+        let err = builder
+            .try_add_unsigned_message_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                vec![],
+                &params,
+            )
+            .expect_err("Witnesses limit should be exceeded");
+        assert_eq!(CheckError::TransactionWitnessesMax, err);
+
+        assert_eq!(params.max_witnesses as usize, builder.inputs().len());
+        assert_eq!(params.max_witnesses as usize, builder.witnesses().len());
+    }
+
+    #[test]
+    fn add_signed_coin_input_uses_provided_witness() {
+        use crate::{Checkable, UniqueIdentifier};
+
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        let secret = SecretKey::random(rng);
+        let owner = Input::owner(&secret.public_key());
+        let (utxo_id, amount, asset_id, tx_pointer) = (rng.gen(), rng.gen(), rng.gen(), rng.gen());
+        let output = Output::coin(rng.gen(), rng.gen(), asset_id);
+
+        // The signer produces the signature out of band, over the final skeleton
+        let skeleton = TransactionBuilder::script(vec![], vec![])
+            .add_unsigned_coin_input(secret, utxo_id, amount, asset_id, tx_pointer, 0)
+            .add_output(output)
+            .finalize_without_signature();
+        let signature = Signature::sign(&secret, &skeleton.hash_to_sign());
+
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_signed_coin_input(
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer,
+                0,
+                Witness::from_signature(&signature),
+            )
+            .add_output(output)
+            .finalize();
+
+        assert_eq!(skeleton.id(), tx.id());
+        assert_eq!(Some(0), tx.inputs()[0].witness_index());
+        assert_eq!(signature.as_ref(), tx.witnesses()[0].as_ref());
+        tx.check_signatures().expect("signature should be valid");
+    }
+
+    #[test]
+    fn add_contract_indexes_output_to_new_input() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        let contract_id = rng.gen();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                0,
+            )
+            .add_output(Output::change(rng.gen(), 0, rng.gen()))
+            .add_contract(rng.gen(), rng.gen(), rng.gen(), rng.gen(), contract_id)
+            .finalize();
+
+        let input_index = match tx.outputs()[1] {
+            Output::Contract { input_index, .. } => input_index,
+            _ => panic!("expected a contract output"),
+        };
+
+        assert_eq!(1, input_index);
+        assert_eq!(
+            Some(&contract_id),
+            tx.inputs()[input_index as usize].contract_id()
+        );
+    }
+}
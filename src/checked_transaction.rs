@@ -2,11 +2,14 @@
 //! It is impossible to construct a checked transaction without performing necessary checks.
 //!
 //! This allows the VM to accept transactions with metadata that have been already verified upstream.
+//!
+//! Without the `std` feature only `Checks::Basic` are available through
+//! [`IntoChecked::into_checked_basic`], so `no_std` clients can still validate balances and fees.
 
 #![allow(non_upper_case_globals)]
 use crate::{
-    field, Chargeable, CheckError, Checkable, ConsensusParameters, Create, Input, Mint, Output,
-    Script, Transaction, TransactionFee,
+    field, Chargeable, CheckError, Checkable, ConsensusParameters, Create, Mint, Output, Script,
+    Transaction, TransactionFee,
 };
 use fuel_types::{AssetId, Word};
 
@@ -81,6 +84,7 @@ impl<Tx: IntoChecked> Checked<Tx> {
     }
 
     /// Performs check of signatures, if not yet.
+    #[cfg(feature = "std")]
     pub fn check_signatures(mut self) -> Result<Self, CheckError> {
         if !self.checks_bitmask.contains(Checks::Signatures) {
             self.transaction.check_signatures()?;
@@ -90,7 +94,7 @@ impl<Tx: IntoChecked> Checked<Tx> {
     }
 }
 
-#[cfg(feature = "internals")]
+#[cfg(all(feature = "internals", feature = "std"))]
 impl<Tx: IntoChecked + Default> Default for Checked<Tx> {
     fn default() -> Self {
         Tx::default()
@@ -135,6 +139,7 @@ pub trait IntoChecked: Checkable + Sized {
     type Metadata: Sized;
 
     /// Returns transaction that passed all `Checks`.
+    #[cfg(feature = "std")]
     fn into_checked(
         self,
         block_height: Word,
@@ -211,6 +216,60 @@ impl From<Checked<Mint>> for CheckedTransaction {
     }
 }
 
+impl CheckedTransaction {
+    /// Check `transaction` without its signatures, as [`IntoChecked::into_checked_basic`].
+    ///
+    /// Available without `std`, so embedded clients can validate the balances and the fees of
+    /// a transaction; [`Checked::check_signatures`] completes the checks where `std` is
+    /// available.
+    pub fn check_without_signatures(
+        transaction: Transaction,
+        block_height: Word,
+        params: &ConsensusParameters,
+    ) -> Result<Self, CheckError> {
+        transaction
+            .into_checked_basic(block_height, params)
+            .map(Self::from)
+    }
+
+    /// Consume the checked transaction, returning the inner transaction without cloning it.
+    pub fn into_transaction(self) -> Transaction {
+        match self {
+            Self::Script(checked) => checked.transaction.into(),
+            Self::Create(checked) => checked.transaction.into(),
+            Self::Mint(checked) => checked.transaction.into(),
+        }
+    }
+
+    /// Consume the checked transaction, returning the inner transaction with the initial free
+    /// balances and the total fee computed by the check.
+    ///
+    /// `Mint` transactions have no balances and don't pay fees.
+    pub fn into_inner(self) -> (Transaction, BTreeMap<AssetId, Word>, Word) {
+        match self {
+            Self::Script(Checked {
+                transaction,
+                metadata,
+                ..
+            }) => (
+                transaction.into(),
+                metadata.initial_free_balances,
+                metadata.fee.total(),
+            ),
+            Self::Create(Checked {
+                transaction,
+                metadata,
+                ..
+            }) => (
+                transaction.into(),
+                metadata.initial_free_balances,
+                metadata.fee.total(),
+            ),
+            Self::Mint(Checked { transaction, .. }) => (transaction.into(), BTreeMap::new(), 0),
+        }
+    }
+}
+
 impl From<CheckedTransaction> for Checked<Transaction> {
     fn from(checked: CheckedTransaction) -> Self {
         match checked {
@@ -297,29 +356,23 @@ where
 {
     let mut balances = BTreeMap::<AssetId, Word>::new();
 
-    // Add up all the inputs for each asset ID
-    for (asset_id, amount) in transaction.inputs().iter().filter_map(|input| match input {
-        // Sum coin inputs
-        Input::CoinPredicate {
-            asset_id, amount, ..
-        }
-        | Input::CoinSigned {
-            asset_id, amount, ..
-        } => Some((*asset_id, amount)),
-        // Sum message inputs
-        Input::MessagePredicate { amount, .. } | Input::MessageSigned { amount, .. } => {
-            Some((AssetId::BASE, amount))
-        }
-        _ => None,
+    // Add up all the coin and message inputs for each asset ID
+    for (asset_id, amount) in transaction.inputs().iter().filter_map(|input| {
+        input
+            .asset_id_with_base(&params.base_asset_id)
+            .zip(input.amount())
     }) {
-        *balances.entry(asset_id).or_default() += amount;
+        let balance = balances.entry(*asset_id).or_default();
+        *balance = balance
+            .checked_add(amount)
+            .ok_or(CheckError::ArithmeticOverflow)?;
     }
 
     // Deduct fee from base asset
     let fee = TransactionFee::checked_from_tx(params, transaction)
         .ok_or(CheckError::ArithmeticOverflow)?;
 
-    let base_asset_balance = balances.entry(AssetId::BASE).or_default();
+    let base_asset_balance = balances.entry(params.base_asset_id).or_default();
 
     *base_asset_balance =
         fee.checked_deduct_total(*base_asset_balance)
@@ -328,7 +381,8 @@ where
                 provided: *base_asset_balance,
             })?;
 
-    // reduce free balances by coin outputs
+    // Add up all the coin outputs for each asset ID
+    let mut spent = BTreeMap::<AssetId, Word>::new();
     for (asset_id, amount) in transaction
         .outputs()
         .iter()
@@ -339,14 +393,24 @@ where
             _ => None,
         })
     {
-        let balance = balances
-            .get_mut(asset_id)
-            .ok_or(CheckError::TransactionOutputCoinAssetIdNotFound(*asset_id))?;
+        if !balances.contains_key(asset_id) {
+            return Err(CheckError::TransactionOutputCoinAssetIdNotFound(*asset_id));
+        }
+
+        let total = spent.entry(*asset_id).or_default();
+        *total = total
+            .checked_add(*amount)
+            .ok_or(CheckError::ArithmeticOverflow)?;
+    }
+
+    // reduce free balances by coin outputs
+    for (asset_id, amount) in spent {
+        let balance = balances.entry(asset_id).or_default();
         *balance = balance
-            .checked_sub(*amount)
+            .checked_sub(amount)
             .ok_or(CheckError::InsufficientInputAmount {
-                asset: *asset_id,
-                expected: *amount,
+                asset: asset_id,
+                expected: amount,
                 provided: *balance,
             })?;
     }
@@ -362,10 +426,16 @@ pub(crate) struct AvailableBalances {
     pub fee: TransactionFee,
 }
 
+// No_std build test: the thumbv6m `alloc` check of the CI fails if the validation of the
+// balances and the fees ever requires `std`.
+#[cfg(not(feature = "std"))]
+const _: fn(Transaction, Word, &ConsensusParameters) -> Result<CheckedTransaction, CheckError> =
+    CheckedTransaction::check_without_signatures;
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{CheckError, Script, TransactionBuilder};
+    use crate::{CheckError, Input, Script, TransactionBuilder};
     use fuel_crypto::SecretKey;
     use quickcheck::TestResult;
     use quickcheck_macros::quickcheck;
@@ -406,6 +476,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_without_signatures_skips_signatures() {
+        use crate::field::Witnesses;
+
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let mut tx = valid_coin_tx(rng, 10, 1000, 1000, 10);
+        tx.witnesses_mut()[0] = vec![0xfa; 64].into();
+        let tx = Transaction::from(tx);
+
+        tx.clone()
+            .into_checked(0, &ConsensusParameters::DEFAULT)
+            .expect_err("Expected invalid signature");
+
+        let checked =
+            CheckedTransaction::check_without_signatures(tx, 0, &ConsensusParameters::DEFAULT)
+                .expect("Expected valid transaction");
+
+        assert_eq!(
+            &Checks::Basic,
+            Checked::<Transaction>::from(checked).checks()
+        );
+    }
+
+    #[test]
+    fn checked_transaction_into_inner_returns_check_results() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let tx: Transaction = valid_coin_tx(rng, 10, 1000, 1000, 10).into();
+
+        let checked: CheckedTransaction = tx
+            .clone()
+            .into_checked(0, &ConsensusParameters::DEFAULT)
+            .expect("Expected valid transaction")
+            .into();
+
+        let metadata = match &checked {
+            CheckedTransaction::Script(script) => script.metadata().clone(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(tx, checked.clone().into_transaction());
+
+        let (tx_p, balances, fee) = checked.into_inner();
+        assert_eq!(tx, tx_p);
+        assert_eq!(metadata.initial_free_balances, balances);
+        assert_eq!(metadata.fee.total(), fee);
+
+        let mint: Transaction = Transaction::mint(Default::default(), vec![]).into();
+        let (mint_p, balances, fee) = CheckedTransaction::from(
+            mint.clone()
+                .into_checked(0, &ConsensusParameters::DEFAULT)
+                .expect("Expected valid transaction"),
+        )
+        .into_inner();
+        assert_eq!(mint, mint_p);
+        assert!(balances.is_empty());
+        assert_eq!(0, fee);
+    }
+
+    #[test]
+    fn checked_tx_deducts_fees_from_configured_base_asset() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let secret = SecretKey::random(rng);
+        let base_asset_id: AssetId = rng.gen();
+        let params = ConsensusParameters::DEFAULT.with_base_asset_id(base_asset_id);
+        let input_amount = 1000;
+
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(10)
+            .gas_limit(1000)
+            .add_unsigned_coin_input(secret, rng.gen(), input_amount, base_asset_id, rng.gen(), 0)
+            .add_unsigned_coin_input(
+                secret,
+                rng.gen(),
+                input_amount,
+                AssetId::default(),
+                rng.gen(),
+                0,
+            )
+            .add_output(Output::change(rng.gen(), 0, base_asset_id))
+            .add_output(Output::change(rng.gen(), 0, AssetId::default()))
+            .finalize();
+
+        let checked = tx
+            .into_checked(0, &params)
+            .expect("Expected valid transaction");
+
+        let balances = &checked.metadata().initial_free_balances;
+        assert_eq!(
+            balances[&base_asset_id],
+            input_amount - checked.metadata().fee.total()
+        );
+        assert_eq!(balances[&AssetId::default()], input_amount);
+    }
+
     #[test]
     fn checked_tx_accepts_valid_signed_message_input_fees() {
         // simple test to ensure a tx that only has a message input can cover fees
@@ -726,6 +890,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checked_tx_fails_if_asset_is_overspent_by_several_coin_outputs() {
+        let input_amount = 1_000;
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let secret = SecretKey::random(rng);
+        let any_asset = rng.gen();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(1)
+            .gas_limit(100)
+            // base asset
+            .add_unsigned_coin_input(
+                secret,
+                rng.gen(),
+                input_amount,
+                AssetId::default(),
+                rng.gen(),
+                0,
+            )
+            .add_output(Output::change(rng.gen(), 0, AssetId::default()))
+            // arbitrary spending asset
+            .add_unsigned_coin_input(secret, rng.gen(), input_amount, any_asset, rng.gen(), 0)
+            .add_output(Output::coin(rng.gen(), 400, any_asset))
+            .add_output(Output::coin(rng.gen(), 400, any_asset))
+            .add_output(Output::coin(rng.gen(), 400, any_asset))
+            .add_output(Output::change(rng.gen(), 0, any_asset))
+            .finalize();
+
+        let err = tx
+            .into_checked(0, &ConsensusParameters::DEFAULT)
+            .expect_err("Expected invalid transaction");
+
+        assert_eq!(
+            CheckError::InsufficientInputAmount {
+                asset: any_asset,
+                expected: 1_200,
+                provided: input_amount
+            },
+            err
+        );
+    }
+
     fn is_valid_max_fee<Tx>(tx: &Tx, params: &ConsensusParameters) -> Result<bool, CheckError>
     where
         Tx: Chargeable + field::Inputs + field::Outputs,
@@ -1,4 +1,10 @@
 use crate::TxPointer;
+
+#[cfg(feature = "alloc")]
+pub use crate::transaction::{
+    INPUT_COIN_FIXED_SIZE, INPUT_CONTRACT_SIZE, INPUT_MESSAGE_FIXED_SIZE, OUTPUT_CCV_SIZE,
+    OUTPUT_CONTRACT_CREATED_SIZE, OUTPUT_CONTRACT_SIZE, OUTPUT_MESSAGE_SIZE,
+};
 use fuel_types::bytes::WORD_SIZE;
 use fuel_types::{Bytes32, Salt};
 
@@ -19,6 +19,33 @@ impl Contract {
         Self::root_from_code(self)
     }
 
+    /// Split the code of the contract into the leaves of its code root, using
+    /// [`Self::leaves_from_code`].
+    pub fn leaves(&self) -> Vec<Bytes8> {
+        Self::leaves_from_code(self.as_ref()).collect()
+    }
+
+    /// Split code into the 8-byte leaves of its code root, zero-padding the last leaf.
+    ///
+    /// Merkle proof tooling can use the leaves to prove a chunk of code is part of the root.
+    pub fn leaves_from_code(bytes: &[u8]) -> impl Iterator<Item = Bytes8> + '_ {
+        bytes.chunks(Bytes8::LEN).map(|c| {
+            if c.len() == Bytes8::LEN {
+                // Safety: checked len chunk
+                unsafe { Bytes8::from_slice_unchecked(c) }
+            } else {
+                // Potential collision with non-padded input. Consider adding an extra leaf
+                // for padding?
+                let mut b = [0u8; 8];
+
+                let l = c.len();
+                b[..l].copy_from_slice(c);
+
+                b.into()
+            }
+        })
+    }
+
     /// Calculate the code root from a contract.
     ///
     /// <https://github.com/FuelLabs/fuel-specs/blob/master/specs/protocol/identifiers.md#contract-id>
@@ -28,25 +55,7 @@ impl Contract {
     {
         let mut tree = BinaryMerkleTree::new();
 
-        bytes
-            .as_ref()
-            .chunks(Bytes8::LEN)
-            .map(|c| {
-                if c.len() == Bytes8::LEN {
-                    // Safety: checked len chunk
-                    unsafe { Bytes8::from_slice_unchecked(c) }
-                } else {
-                    // Potential collision with non-padded input. Consider adding an extra leaf
-                    // for padding?
-                    let mut b = [0u8; 8];
-
-                    let l = c.len();
-                    b[..l].copy_from_slice(c);
-
-                    b.into()
-                }
-            })
-            .for_each(|l| tree.push(l.as_ref()));
+        Self::leaves_from_code(bytes.as_ref()).for_each(|l| tree.push(l.as_ref()));
 
         tree.root().into()
     }
@@ -174,6 +183,28 @@ mod tests {
         code_root == contract_root
     }
 
+    #[test]
+    fn leaves_reproduce_code_root() {
+        let mut rng = StdRng::seed_from_u64(100);
+
+        for code_len in [0, 4, 8, 12, 400] {
+            let mut code = alloc::vec![0u8; code_len];
+            rng.fill_bytes(code.as_mut_slice());
+            let contract = Contract::from(code.clone());
+
+            let leaves = contract.leaves();
+            assert_eq!(num_integer::div_ceil(code_len, Bytes8::LEN), leaves.len());
+            assert_eq!(
+                code.as_slice(),
+                &leaves.iter().flat_map(|l| l.iter()).copied().collect_vec()[..code_len]
+            );
+
+            let mut tree = BinaryMerkleTree::new();
+            leaves.iter().for_each(|l| tree.push(l.as_ref()));
+            assert_eq!(contract.root(), Bytes32::from(tree.root()));
+        }
+    }
+
     #[rstest]
     fn state_root_snapshot(
         #[values(Vec::new(), vec![Bytes64::new([1u8; 64])])] state_slot_bytes: Vec<Bytes64>,
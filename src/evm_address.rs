@@ -0,0 +1,215 @@
+use fuel_types::bytes::{self, SizedBytes};
+use fuel_types::Bytes20;
+
+use core::{fmt, ops::Deref};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "random")]
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+/// Address of an account on an EVM chain, e.g. the recipient of a message relayed to Ethereum.
+///
+/// It is encoded as its 20 bytes followed by zeroes up to a word, like any other byte array of
+/// the transactions.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvmAddress(Bytes20);
+
+impl EvmAddress {
+    pub const LEN: usize = Bytes20::LEN;
+
+    pub const fn new(address: Bytes20) -> Self {
+        Self(address)
+    }
+
+    /// EIP-55 representation of the address: `0x` followed by its hex digits, where the case of
+    /// every letter is set by the Keccak-256 hash of the lowercase digits.
+    #[cfg(feature = "alloc")]
+    pub fn to_checksum_string(&self) -> String {
+        use sha3::{Digest, Keccak256};
+
+        let hex = alloc::format!("{:x}", self.0);
+        let hash = Keccak256::digest(hex.as_bytes());
+
+        let mut checksum = String::with_capacity(2 + hex.len());
+        checksum.push_str("0x");
+
+        hex.chars().enumerate().for_each(|(i, c)| {
+            let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+
+            if nibble >= 8 {
+                checksum.push(c.to_ascii_uppercase());
+            } else {
+                checksum.push(c);
+            }
+        });
+
+        checksum
+    }
+}
+
+impl Deref for EvmAddress {
+    type Target = Bytes20;
+
+    fn deref(&self) -> &Bytes20 {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for EvmAddress {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl From<Bytes20> for EvmAddress {
+    fn from(address: Bytes20) -> Self {
+        Self(address)
+    }
+}
+
+impl From<[u8; EvmAddress::LEN]> for EvmAddress {
+    fn from(bytes: [u8; EvmAddress::LEN]) -> Self {
+        Self(bytes.into())
+    }
+}
+
+impl From<EvmAddress> for Bytes20 {
+    fn from(address: EvmAddress) -> Self {
+        address.0
+    }
+}
+
+impl TryFrom<&[u8]> for EvmAddress {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Bytes20::try_from(bytes).map(Self)
+    }
+}
+
+#[cfg(feature = "random")]
+impl Distribution<EvmAddress> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> EvmAddress {
+        EvmAddress(rng.gen())
+    }
+}
+
+impl fmt::LowerHex for EvmAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for EvmAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl SizedBytes for EvmAddress {
+    fn serialized_size(&self) -> usize {
+        bytes::padded_len_usize(Self::LEN)
+    }
+}
+
+#[cfg(feature = "std")]
+impl io::Write for EvmAddress {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.serialized_size();
+        if buf.len() < n {
+            return Err(bytes::eof());
+        }
+
+        let (address, padding) = buf[..n].split_at(Self::LEN);
+        if padding.iter().any(|b| *b != 0) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The padding of the EVM address isn't zeroed!",
+            ));
+        }
+
+        // Safety: buf len is checked
+        self.0 = unsafe { Bytes20::from_slice_unchecked(address) };
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl io::Read for EvmAddress {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.serialized_size();
+        if buf.len() < n {
+            return Err(bytes::eof());
+        }
+
+        buf[..Self::LEN].copy_from_slice(self.as_ref());
+        buf[Self::LEN..n].fill(0);
+
+        Ok(n)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn address(hex: &str) -> EvmAddress {
+        hex.parse::<Bytes20>().expect("hex encoded address").into()
+    }
+
+    #[test]
+    fn checksum_string_follows_eip55() {
+        // Test vectors of EIP-55
+        let cases = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+            "0x52908400098527886E0F7030069857D2E4169EE7",
+            "0xde709f2102306220921060314715629080e2fb77",
+        ];
+
+        for checksum in cases {
+            let address = address(checksum);
+
+            assert_eq!(checksum, address.to_checksum_string());
+            assert_eq!(checksum[2..].to_ascii_lowercase(), format!("{:x}", address));
+        }
+    }
+
+    #[test]
+    fn canonical_encoding_round_trip() {
+        use fuel_types::bytes::{Deserializable, SerializableVec};
+
+        let mut address = address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+
+        let bytes = address.to_bytes();
+        assert_eq!(bytes::padded_len_usize(EvmAddress::LEN), bytes.len());
+        assert_eq!(address.as_ref(), &bytes[..EvmAddress::LEN]);
+        assert!(bytes[EvmAddress::LEN..].iter().all(|b| *b == 0));
+
+        let address_p = EvmAddress::from_bytes(&bytes).expect("failed to decode address");
+        assert_eq!(address, address_p);
+
+        let mut dirty = bytes.clone();
+        *dirty.last_mut().unwrap() = 1;
+        let err = EvmAddress::from_bytes(&dirty).expect_err("non-zero padding");
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        assert!(EvmAddress::from_bytes(&bytes[..EvmAddress::LEN]).is_err());
+    }
+}
@@ -11,8 +11,10 @@
 extern crate alloc;
 
 pub mod consts;
+mod evm_address;
 mod tx_pointer;
 
+pub use evm_address::EvmAddress;
 pub use fuel_asm::{InstructionResult, PanicReason};
 pub use fuel_types::{
     Address, AssetId, Bytes32, Bytes4, Bytes64, Bytes8, ContractId, MessageId, Salt, Word,
@@ -31,7 +33,7 @@ mod receipt;
 #[cfg(feature = "alloc")]
 mod transaction;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 mod checked_transaction;
 
 #[cfg(feature = "builder")]
@@ -42,19 +44,27 @@ pub use receipt::{Receipt, ScriptExecutionResult};
 
 #[cfg(feature = "alloc")]
 pub use transaction::{
-    field, Cacheable, Chargeable, CheckError, Checkable, ConsensusParameters, Create, Executable,
-    Input, InputRepr, Mint, Output, OutputRepr, Script, StorageSlot, Transaction, TransactionFee,
-    TransactionRepr, TxId, UtxoId, Witness,
+    field, Cacheable, Chargeable, CheckError, Checkable, Conflict, ConsensusParameters,
+    ConsensusParametersError, Create, CreateCheckedMetadata, Executable, GasCosts, Input,
+    InputColumns, InputRepr, Mint, Output, OutputColumns, OutputRepr, ParametersWarning,
+    PredicateContext, Script, ScriptCheckedMetadata, SignMask, SignatureCheck, StorageSlot,
+    Transaction, TransactionFee, TransactionRepr, TxId, UtxoId, Witness,
 };
 
+#[cfg(feature = "serde")]
+pub use transaction::TaggedOutput;
+
 #[cfg(feature = "std")]
-pub use transaction::{CreateCheckedMetadata, ScriptCheckedMetadata, Signable, UniqueIdentifier};
+pub use transaction::{
+    DryRunReport, SharedTransaction, Signable, SigningSummary, SigningSummaryEntry,
+    TransactionHeader, UniqueIdentifier,
+};
 
 #[cfg(feature = "alloc")]
 #[allow(deprecated)]
 pub use transaction::consensus_parameters::default_parameters;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub use checked_transaction::{Checked, CheckedMetadata, CheckedTransaction, IntoChecked};
 
 #[cfg(feature = "alloc")]
@@ -7,6 +7,9 @@ use fuel_types::{Address, AssetId, Bytes32, ContractId, MessageId, Word};
 #[cfg(feature = "std")]
 mod receipt_std;
 
+#[cfg(feature = "serde")]
+mod receipt_abi;
+
 mod receipt_repr;
 mod script_result;
 
@@ -683,3 +686,38 @@ impl SizedBytes for Receipt {
         Self::variant_len_without_data(ReceiptRepr::from(self)) + WORD_SIZE + data_len
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn program_counters() {
+        let call = Receipt::call(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            0,
+            0,
+            0,
+            0x1000,
+            0x0800,
+        );
+        assert_eq!(Some(0x1000), call.pc());
+        assert_eq!(Some(0x0800), call.is());
+
+        let panic = Receipt::panic(
+            Default::default(),
+            InstructionResult::success(),
+            0x2000,
+            0x1800,
+        );
+        assert_eq!(Some(0x2000), panic.pc());
+        assert_eq!(Some(0x1800), panic.is());
+
+        let result = Receipt::script_result(ScriptExecutionResult::Success, 0);
+        assert_eq!(None, result.pc());
+        assert_eq!(None, result.is());
+    }
+}
@@ -0,0 +1,351 @@
+use super::{Receipt, ScriptExecutionResult};
+
+use fuel_asm::InstructionResult;
+use fuel_types::{Address, AssetId, Bytes32, ContractId, MessageId, Word};
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+impl Receipt {
+    /// Serialize the receipt as the JSON documented by the Fuel ABI.
+    ///
+    /// The variant is stored in a `type` field, words, hashes and byte arrays are `0x` prefixed
+    /// hex strings and the `id` of a `Call` is named `from`.
+    ///
+    /// If an error happens, a JSON string with the error description will be returned
+    pub fn to_abi_json(&self) -> String {
+        serde_json::to_string(&AbiRef(self)).unwrap_or_else(|e| format!(r#"{{"error": "{}"}}"#, e))
+    }
+
+    /// Attempt to deserialize a receipt from the JSON documented by the Fuel ABI, returning
+    /// `None` if it fails
+    pub fn from_abi_json<J>(json: J) -> Option<Self>
+    where
+        J: AsRef<str>,
+    {
+        serde_json::from_str::<Abi>(json.as_ref())
+            .ok()
+            .map(|abi| abi.0)
+    }
+}
+
+struct AbiRef<'a>(&'a Receipt);
+
+impl Serialize for AbiRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ReceiptAbi::serialize(self.0, serializer)
+    }
+}
+
+#[derive(Deserialize)]
+struct Abi(#[serde(with = "ReceiptAbi")] Receipt);
+
+/// Field mapping of the receipts to the ABI.
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Receipt", tag = "type")]
+enum ReceiptAbi {
+    Call {
+        #[serde(rename = "from", with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_bytes")]
+        to: ContractId,
+        #[serde(with = "hex_word")]
+        amount: Word,
+        #[serde(with = "hex_bytes")]
+        asset_id: AssetId,
+        #[serde(with = "hex_word")]
+        gas: Word,
+        #[serde(with = "hex_word")]
+        param1: Word,
+        #[serde(with = "hex_word")]
+        param2: Word,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+    },
+
+    Return {
+        #[serde(with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_word")]
+        val: Word,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+    },
+
+    ReturnData {
+        #[serde(with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_word")]
+        ptr: Word,
+        #[serde(with = "hex_word")]
+        len: Word,
+        #[serde(with = "hex_bytes")]
+        digest: Bytes32,
+        #[serde(with = "hex_bytes")]
+        data: Vec<u8>,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+    },
+
+    Panic {
+        #[serde(with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_word")]
+        reason: InstructionResult,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+        #[serde(with = "hex_bytes_option", default)]
+        contract_id: Option<ContractId>,
+    },
+
+    Revert {
+        #[serde(with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_word")]
+        ra: Word,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+    },
+
+    Log {
+        #[serde(with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_word")]
+        ra: Word,
+        #[serde(with = "hex_word")]
+        rb: Word,
+        #[serde(with = "hex_word")]
+        rc: Word,
+        #[serde(with = "hex_word")]
+        rd: Word,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+    },
+
+    LogData {
+        #[serde(with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_word")]
+        ra: Word,
+        #[serde(with = "hex_word")]
+        rb: Word,
+        #[serde(with = "hex_word")]
+        ptr: Word,
+        #[serde(with = "hex_word")]
+        len: Word,
+        #[serde(with = "hex_bytes")]
+        digest: Bytes32,
+        #[serde(with = "hex_bytes")]
+        data: Vec<u8>,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+    },
+
+    Transfer {
+        #[serde(with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_bytes")]
+        to: ContractId,
+        #[serde(with = "hex_word")]
+        amount: Word,
+        #[serde(with = "hex_bytes")]
+        asset_id: AssetId,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+    },
+
+    TransferOut {
+        #[serde(with = "hex_bytes")]
+        id: ContractId,
+        #[serde(with = "hex_bytes")]
+        to: Address,
+        #[serde(with = "hex_word")]
+        amount: Word,
+        #[serde(with = "hex_bytes")]
+        asset_id: AssetId,
+        #[serde(with = "hex_word")]
+        pc: Word,
+        #[serde(with = "hex_word")]
+        is: Word,
+    },
+
+    ScriptResult {
+        #[serde(with = "hex_word")]
+        result: ScriptExecutionResult,
+        #[serde(with = "hex_word")]
+        gas_used: Word,
+    },
+
+    MessageOut {
+        #[serde(with = "hex_bytes")]
+        message_id: MessageId,
+        #[serde(with = "hex_bytes")]
+        sender: Address,
+        #[serde(with = "hex_bytes")]
+        recipient: Address,
+        #[serde(with = "hex_word")]
+        amount: Word,
+        #[serde(with = "hex_bytes")]
+        nonce: Bytes32,
+        #[serde(with = "hex_word")]
+        len: Word,
+        #[serde(with = "hex_bytes")]
+        digest: Bytes32,
+        #[serde(with = "hex_bytes")]
+        data: Vec<u8>,
+    },
+}
+
+/// Words, and the values encoded as words, as `0x` prefixed 16 digits hex strings.
+mod hex_word {
+    use fuel_types::Word;
+
+    use alloc::format;
+    use alloc::string::String;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Into<Word>,
+        S: Serializer,
+    {
+        let word: Word = (*value).into();
+
+        serializer.serialize_str(&format!("0x{:016x}", word))
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<Word>,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let digits = s
+            .strip_prefix("0x")
+            .ok_or_else(|| D::Error::custom("expected a 0x prefixed word"))?;
+
+        Word::from_str_radix(digits, 16)
+            .map(T::from)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Hashes and byte arrays as `0x` prefixed hex strings.
+mod hex_bytes {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt::Write;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut hex = String::with_capacity(2 + 2 * bytes.len());
+        hex.push_str("0x");
+        bytes
+            .iter()
+            .for_each(|b| write!(hex, "{:02x}", b).expect("writing to a string can't fail"));
+
+        hex
+    }
+
+    pub fn decode<E>(hex: &str) -> Result<Vec<u8>, E>
+    where
+        E: Error,
+    {
+        let digits = hex
+            .strip_prefix("0x")
+            .ok_or_else(|| E::custom("expected 0x prefixed bytes"))?;
+
+        if digits.len() % 2 != 0 {
+            return Err(E::custom("expected an even number of hex digits"));
+        }
+
+        let nibble = |digit: u8| {
+            char::from(digit)
+                .to_digit(16)
+                .ok_or_else(|| E::custom("expected hex digits"))
+        };
+
+        // Decode the bytes of the string: a non ASCII char can't be split into digit pairs
+        digits
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| Ok((nibble(pair[0])? << 4 | nibble(pair[1])?) as u8))
+            .collect()
+    }
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]>,
+        S: Serializer,
+    {
+        serializer.serialize_str(&encode(value.as_ref()))
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: for<'a> TryFrom<&'a [u8]>,
+        D: Deserializer<'de>,
+    {
+        let bytes = decode(&String::deserialize(deserializer)?)?;
+
+        T::try_from(bytes.as_slice()).map_err(|_| D::Error::custom("unexpected bytes length"))
+    }
+}
+
+/// Optional hashes as `0x` prefixed hex strings or `null`.
+mod hex_bytes_option {
+    use super::hex_bytes;
+
+    use alloc::string::String;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]>,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => hex_bytes::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: for<'a> TryFrom<&'a [u8]>,
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|hex| {
+                let bytes = hex_bytes::decode::<D::Error>(&hex)?;
+
+                T::try_from(bytes.as_slice())
+                    .map_err(|_| D::Error::custom("unexpected bytes length"))
+            })
+            .transpose()
+    }
+}
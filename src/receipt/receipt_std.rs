@@ -5,8 +5,46 @@ use fuel_types::bytes::{self, SizedBytes, WORD_SIZE};
 use fuel_types::Word;
 
 use crate::receipt::script_result::ScriptExecutionResult;
+use fuel_merkle::binary::in_memory::MerkleTree as BinaryMerkleTree;
+use fuel_types::bytes::SerializableVec;
+use fuel_types::Bytes32;
 use std::io::{self, Write};
 
+impl Receipt {
+    /// Root of the binary Merkle tree whose leaves are the serialized `receipts`, as stored in
+    /// the receipts root of a script transaction.
+    pub fn root<'a, I>(receipts: I) -> Bytes32
+    where
+        I: IntoIterator<Item = &'a Receipt>,
+    {
+        let mut tree = BinaryMerkleTree::new();
+
+        receipts
+            .into_iter()
+            .for_each(|receipt| tree.push(receipt.clone().to_bytes().as_slice()));
+
+        tree.root().into()
+    }
+
+    /// Binary Merkle root of the messages emitted by a transaction.
+    ///
+    /// Message ids are only known after execution, so the leaves are the ids of the
+    /// [`Receipt::MessageOut`] in `receipts`, in the order they were produced.
+    pub fn message_root<'a, I>(receipts: I) -> Bytes32
+    where
+        I: IntoIterator<Item = &'a Receipt>,
+    {
+        let mut tree = BinaryMerkleTree::new();
+
+        receipts
+            .into_iter()
+            .filter_map(Receipt::message_id)
+            .for_each(|id| tree.push(id.as_ref()));
+
+        tree.root().into()
+    }
+}
+
 impl io::Read for Receipt {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let len = self.serialized_size();
@@ -454,3 +492,33 @@ impl TryFrom<Word> for ReceiptRepr {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_root_depends_on_message_order() {
+        let tx_id = Bytes32::from([0xfa; 32]);
+        let message = |idx, amount| {
+            Receipt::message_out_from_tx_output(
+                &tx_id,
+                idx,
+                Default::default(),
+                Default::default(),
+                amount,
+                vec![],
+            )
+        };
+
+        let a = message(0, 10);
+        let b = message(1, 20);
+        let other = Receipt::ret(Default::default(), 0, 0, 0);
+
+        let root = Receipt::message_root(&[a.clone(), other.clone(), b.clone()]);
+
+        assert_ne!(Bytes32::zeroed(), root);
+        assert_eq!(root, Receipt::message_root(&[a.clone(), b.clone()]));
+        assert_ne!(root, Receipt::message_root(&[b, a]));
+    }
+}
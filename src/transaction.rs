@@ -1,7 +1,8 @@
-use fuel_crypto::PublicKey;
+use fuel_crypto::{PublicKey, Signature};
 use fuel_types::bytes::SizedBytes;
 use fuel_types::{Address, AssetId, Bytes32, Salt, Word};
 
+use alloc::collections::BTreeMap;
 use alloc::vec::{IntoIter, Vec};
 use itertools::Itertools;
 
@@ -9,30 +10,56 @@ mod checkable;
 mod fee;
 mod metadata;
 mod repr;
+mod sign_mask;
 mod types;
 
+#[cfg(feature = "std")]
+mod dry_run;
+
 #[cfg(feature = "std")]
 mod id;
 
+#[cfg(feature = "std")]
+mod shared;
+
+#[cfg(feature = "std")]
+mod summary;
+
 #[cfg(feature = "std")]
 mod txio;
 
 pub mod consensus_parameters;
 
-pub use checkable::{CheckError, Checkable};
-pub use consensus_parameters::ConsensusParameters;
+pub use checkable::{CheckError, Checkable, Conflict, SignatureCheck};
+pub use consensus_parameters::{
+    ConsensusParameters, ConsensusParametersError, GasCosts, ParametersWarning,
+};
 pub use fee::{Chargeable, TransactionFee};
 pub use metadata::Cacheable;
 pub use repr::TransactionRepr;
+pub use sign_mask::SignMask;
 pub use types::{
-    Create, Input, InputRepr, Mint, Output, OutputRepr, Script, StorageSlot, UtxoId, Witness,
+    Create, Input, InputColumns, InputRepr, Mint, Output, OutputColumns, OutputRepr,
+    PredicateContext, Script, StorageSlot, UtxoId, Witness, INPUT_COIN_FIXED_SIZE,
+    INPUT_CONTRACT_SIZE, INPUT_MESSAGE_FIXED_SIZE, OUTPUT_CCV_SIZE, OUTPUT_CONTRACT_CREATED_SIZE,
+    OUTPUT_CONTRACT_SIZE, OUTPUT_MESSAGE_SIZE,
 };
 
+#[cfg(feature = "serde")]
+pub use types::TaggedOutput;
+
 use crate::TxPointer;
 
+#[cfg(feature = "std")]
+pub use dry_run::DryRunReport;
 #[cfg(feature = "std")]
 pub use id::{Signable, UniqueIdentifier};
 #[cfg(feature = "std")]
+pub use shared::SharedTransaction;
+#[cfg(feature = "std")]
+pub use summary::{SigningSummary, SigningSummaryEntry};
+#[cfg(feature = "std")]
+pub use txio::TransactionHeader;
 pub use types::{CreateCheckedMetadata, ScriptCheckedMetadata};
 
 /// Identification of transaction (also called transaction hash)
@@ -54,6 +81,21 @@ impl Default for Transaction {
 }
 
 impl Transaction {
+    /// Serialization version of the transactions produced by this crate.
+    ///
+    /// The encoding doesn't carry a version word yet, so the current layout is version `0`.
+    pub const VERSION: u16 = 0;
+
+    /// Serialization versions this crate is able to decode.
+    pub const SUPPORTED_VERSIONS: &'static [u16] = &[Self::VERSION];
+
+    /// Whether transactions serialized with version `v` can be decoded by this crate.
+    ///
+    /// Peers can use it to negotiate a version before attempting to decode.
+    pub fn can_decode_version(v: u16) -> bool {
+        Self::SUPPORTED_VERSIONS.contains(&v)
+    }
+
     pub const fn script(
         gas_price: Word,
         gas_limit: Word,
@@ -125,6 +167,38 @@ impl Transaction {
         }
     }
 
+    /// Smallest script transaction passing [`Checkable::check_without_signatures`] under
+    /// `params`, to be used as a placeholder in tests.
+    ///
+    /// It runs the single `RET` of [`Script::default`], spends one coin of the base asset
+    /// owned by the zero address, and returns it with a change output. The gas price is zero,
+    /// so the coin covers the fee. The coin witness is a zeroed signature, so the
+    /// signature check doesn't pass.
+    pub fn minimal_valid(params: &ConsensusParameters) -> Self {
+        use field::{GasLimit, Inputs, Outputs, Witnesses};
+
+        let mut script = Script::default();
+
+        *script.gas_limit_mut() = params.max_gas_per_tx;
+        script.inputs_mut().push(Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            1,
+            params.base_asset_id,
+            Default::default(),
+            0,
+            0,
+        ));
+        script
+            .outputs_mut()
+            .push(Output::change(Default::default(), 0, params.base_asset_id));
+        script
+            .witnesses_mut()
+            .push(Witness::from_signature(&Signature::default()));
+
+        script.into()
+    }
+
     /// Convert the type into a JSON string
     ///
     /// This is implemented as infallible because serde_json will fail only if the type can't
@@ -137,6 +211,40 @@ impl Transaction {
         serde_json::to_string(self).unwrap_or_else(|e| alloc::format!(r#"{{"error": "{}"}}"#, e))
     }
 
+    /// Serialize the transaction as JSON with the keys of every object sorted, so the same
+    /// transaction always produces byte-identical JSON.
+    ///
+    /// Errors are reported as in [`Self::to_json`].
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    pub fn to_json_canonical(&self) -> alloc::string::String {
+        fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+            use serde_json::Value;
+
+            match value {
+                Value::Object(map) => {
+                    let mut entries = map.into_iter().collect_vec();
+                    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+                    Value::Object(
+                        entries
+                            .into_iter()
+                            .map(|(key, value)| (key, canonicalize(value)))
+                            .collect(),
+                    )
+                }
+                Value::Array(values) => {
+                    Value::Array(values.into_iter().map(canonicalize).collect())
+                }
+                value => value,
+            }
+        }
+
+        serde_json::to_value(self)
+            .map(canonicalize)
+            .and_then(|value| serde_json::to_string(&value))
+            .unwrap_or_else(|e| alloc::format!(r#"{{"error": "{}"}}"#, e))
+    }
+
     /// Attempt to deserialize a transaction from a JSON string, returning `None` if it fails
     #[cfg(all(feature = "serde", feature = "alloc"))]
     pub fn from_json<J>(json: J) -> Option<Self>
@@ -203,29 +311,846 @@ impl Transaction {
             _ => None,
         }
     }
+
+    /// Fixed gas cost of processing the transaction, excluding its execution.
+    ///
+    /// Accounts for the signature verification of the signed inputs and for the processing of
+    /// every input and output, according to [`ConsensusParameters::gas_costs`].
+    pub fn intrinsic_gas(&self, params: &ConsensusParameters) -> Word {
+        use field::{Inputs, Outputs};
+
+        let (inputs, outputs): (&[Input], &[Output]) = match self {
+            Self::Script(script) => (script.inputs(), script.outputs()),
+            Self::Create(create) => (create.inputs(), create.outputs()),
+            Self::Mint(mint) => (&[], mint.outputs()),
+        };
+
+        intrinsic_gas(inputs, outputs, params)
+    }
+
+    /// Index and estimated gas of every predicate input, as [`Input::estimate_predicate_gas`].
+    ///
+    /// The inputs don't declare the gas used by their predicates, so these are heuristics for
+    /// wallets, not budgets: a predicate may use more gas than estimated.
+    pub fn predicate_gas_requirements(&self, params: &ConsensusParameters) -> Vec<(usize, Word)> {
+        use field::Inputs;
+
+        let inputs: &[Input] = match self {
+            Self::Script(script) => script.inputs(),
+            Self::Create(create) => create.inputs(),
+            Self::Mint(_) => &[],
+        };
+
+        inputs
+            .iter()
+            .enumerate()
+            .filter(|(_, input)| input.is_coin_predicate() || input.is_message_predicate())
+            .map(|(index, input)| (index, input.estimate_predicate_gas(params)))
+            .collect()
+    }
+
+    /// Validate the input at `index` without its signature, against the outputs and witnesses
+    /// of the transaction.
+    ///
+    /// Builders can check every input as it is added instead of checking the whole
+    /// transaction again. Fails with [`CheckError::InputIndexBounds`] if there is no such input.
+    pub fn validate_input_at(
+        &self,
+        index: usize,
+        params: &ConsensusParameters,
+    ) -> Result<(), CheckError> {
+        use field::{Inputs, Outputs, Witnesses};
+
+        let (inputs, outputs, witnesses): (&[Input], &[Output], &[Witness]) = match self {
+            Self::Script(script) => (script.inputs(), script.outputs(), script.witnesses()),
+            Self::Create(create) => (create.inputs(), create.outputs(), create.witnesses()),
+            Self::Mint(_) => (&[], &[], &[]),
+        };
+
+        inputs
+            .get(index)
+            .ok_or(CheckError::InputIndexBounds { index })?
+            .check_without_signature(index, outputs, witnesses, params)
+    }
+
+    /// Distinct addresses that must sign the transaction, in the order of their first input.
+    ///
+    /// These are the owners of the signed coin inputs and the recipients of the signed message
+    /// inputs. Predicate and contract inputs need no signature.
+    pub fn required_signers(&self) -> Vec<Address> {
+        use field::Inputs;
+
+        let inputs: &[Input] = match self {
+            Self::Script(script) => script.inputs(),
+            Self::Create(create) => create.inputs(),
+            Self::Mint(_) => &[],
+        };
+
+        let mut signers = Vec::new();
+
+        inputs
+            .iter()
+            .filter_map(|input| match input {
+                Input::CoinSigned { owner, .. } => Some(*owner),
+                Input::MessageSigned { recipient, .. } => Some(*recipient),
+                _ => None,
+            })
+            .for_each(|signer| {
+                if !signers.contains(&signer) {
+                    signers.push(signer);
+                }
+            });
+
+        signers
+    }
+
+    /// Total amount of `asset_id` provided by the inputs; message inputs provide
+    /// `base_asset_id`.
+    ///
+    /// Returns [`CheckError::ArithmeticOverflow`] if the sum doesn't fit in a [`Word`].
+    pub fn total_input_value(
+        &self,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> Result<Word, CheckError> {
+        use field::Inputs;
+
+        let inputs: &[Input] = match self {
+            Self::Script(script) => script.inputs(),
+            Self::Create(create) => create.inputs(),
+            Self::Mint(_) => &[],
+        };
+
+        inputs
+            .iter()
+            .filter(|input| input.asset_id_with_base(base_asset_id) == Some(asset_id))
+            .filter_map(Input::amount)
+            .try_fold(0 as Word, |total, amount| total.checked_add(amount))
+            .ok_or(CheckError::ArithmeticOverflow)
+    }
+
+    /// Total amount of `asset_id` provided by the inputs; message inputs provide
+    /// [`AssetId::BASE`], as in [`Input::asset_id`].
+    ///
+    /// Shorthand of [`Self::total_input_value`] for the default base asset, returning
+    /// [`CheckError::ArithmeticOverflow`] if the sum doesn't fit in a [`Word`].
+    pub fn total_input_amount(&self, asset_id: &AssetId) -> Result<Word, CheckError> {
+        self.total_input_value(asset_id, &AssetId::BASE)
+    }
+
+    /// Change left of `asset_id` once the coin outputs and `fee` are paid; message inputs
+    /// provide `base_asset_id`.
+    ///
+    /// Returns [`CheckError::InsufficientInputAmount`] if the coin outputs and the fee exceed the
+    /// inputs, and [`CheckError::ArithmeticOverflow`] if a sum doesn't fit in a [`Word`].
+    pub fn try_balance_change(
+        &self,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+        fee: Word,
+    ) -> Result<Word, CheckError> {
+        use field::Outputs;
+
+        let outputs: &[Output] = match self {
+            Self::Script(script) => script.outputs(),
+            Self::Create(create) => create.outputs(),
+            Self::Mint(mint) => mint.outputs(),
+        };
+
+        let provided = self.total_input_value(asset_id, base_asset_id)?;
+        let expected = outputs
+            .iter()
+            .filter_map(|output| match output {
+                Output::Coin {
+                    asset_id: output_asset_id,
+                    amount,
+                    ..
+                } if output_asset_id == asset_id => Some(*amount),
+                _ => None,
+            })
+            .try_fold(fee, |total, amount| total.checked_add(amount))
+            .ok_or(CheckError::ArithmeticOverflow)?;
+
+        provided
+            .checked_sub(expected)
+            .ok_or(CheckError::InsufficientInputAmount {
+                asset: *asset_id,
+                expected,
+                provided,
+            })
+    }
+
+    /// Amount of every asset entering and leaving the transaction, as `(total_in, total_out)`.
+    ///
+    /// Inputs are accounted like [`Self::total_input_value`]; outputs are the coin, change and
+    /// variable outputs. Sums saturate at [`Word::MAX`].
+    pub fn asset_flows(&self, base_asset_id: &AssetId) -> BTreeMap<AssetId, (Word, Word)> {
+        use field::{Inputs, Outputs};
+
+        let (inputs, outputs): (&[Input], &[Output]) = match self {
+            Self::Script(script) => (script.inputs(), script.outputs()),
+            Self::Create(create) => (create.inputs(), create.outputs()),
+            Self::Mint(mint) => (&[], mint.outputs()),
+        };
+
+        let mut flows = BTreeMap::<AssetId, (Word, Word)>::new();
+
+        inputs.iter().for_each(|input| {
+            if let (Some(asset_id), Some(amount)) =
+                (input.asset_id_with_base(base_asset_id), input.amount())
+            {
+                let (total_in, _) = flows.entry(*asset_id).or_default();
+                *total_in = total_in.saturating_add(amount);
+            }
+        });
+
+        outputs.iter().for_each(|output| match output {
+            Output::Coin {
+                asset_id, amount, ..
+            }
+            | Output::Change {
+                asset_id, amount, ..
+            }
+            | Output::Variable {
+                asset_id, amount, ..
+            } => {
+                let (_, total_out) = flows.entry(*asset_id).or_default();
+                *total_out = total_out.saturating_add(*amount);
+            }
+            _ => (),
+        });
+
+        flows
+    }
+
+    /// Outputs that become spendable UTXOs once the transaction is included, paired with
+    /// their UTXO id.
+    ///
+    /// Only coin, change and variable outputs are spendable; contract, contract created and
+    /// message outputs are skipped, but still count towards the output index.
+    pub fn created_utxos(&self, txid: &Bytes32) -> Vec<(UtxoId, &Output)> {
+        use field::Outputs;
+
+        let outputs = match self {
+            Self::Script(script) => script.outputs(),
+            Self::Create(create) => create.outputs(),
+            Self::Mint(mint) => mint.outputs(),
+        };
+
+        outputs
+            .iter()
+            .enumerate()
+            .filter(|(_, output)| {
+                matches!(
+                    output,
+                    Output::Coin { .. } | Output::Change { .. } | Output::Variable { .. }
+                )
+            })
+            .map(|(index, output)| (UtxoId::new(*txid, index as u8), output))
+            .collect()
+    }
+
+    /// Maximum fee of the transaction per serialized byte, suitable as a priority key.
+    ///
+    /// `Mint` transactions don't pay fees, so their fee per byte is zero.
+    pub fn fee_per_byte(&self, params: &ConsensusParameters) -> Result<f64, CheckError> {
+        let fee = self.checked_fee(params)?;
+
+        let size = self.serialized_size();
+        if size == 0 {
+            return Ok(0.0);
+        }
+
+        Ok(fee.total() as f64 / size as f64)
+    }
+
+    /// Sum of the maximum fees of a block of transactions, i.e. the value collected by the
+    /// block producer in its coinbase.
+    ///
+    /// `Mint` transactions don't pay fees, so they don't contribute to the sum.
+    pub fn total_block_fees(
+        txs: &[Transaction],
+        params: &ConsensusParameters,
+    ) -> Result<Word, CheckError> {
+        txs.iter().try_fold(0 as Word, |total, tx| {
+            let fee = tx.checked_fee(params)?;
+
+            total
+                .checked_add(fee.total())
+                .ok_or(CheckError::ArithmeticOverflow)
+        })
+    }
+
+    /// Whether the transaction makes the same transfers as `other` for a lower maximum fee.
+    ///
+    /// Both make the same transfers if they output the same amount of every asset, as
+    /// accounted by [`Self::asset_flows`]; the inputs funding them may differ. Returns `false`
+    /// if the fee of either transaction overflows.
+    pub fn is_more_efficient_than(&self, other: &Self, params: &ConsensusParameters) -> bool {
+        let transfers = |tx: &Self| {
+            tx.asset_flows(&params.base_asset_id)
+                .into_iter()
+                .filter(|(_, (_, total_out))| *total_out != 0)
+                .map(|(asset_id, (_, total_out))| (asset_id, total_out))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        if transfers(self) != transfers(other) {
+            return false;
+        }
+
+        match (self.checked_fee(params), other.checked_fee(params)) {
+            (Ok(fee), Ok(other_fee)) => fee.total() < other_fee.total(),
+            _ => false,
+        }
+    }
+
+    /// Fee of the transaction as if its gas price were `gas_price`, without mutating it.
+    ///
+    /// `Mint` transactions don't pay fees, so their fee is zero.
+    pub fn fee_at_price(
+        &self,
+        gas_price: Word,
+        params: &ConsensusParameters,
+    ) -> Result<TransactionFee, CheckError> {
+        let (metered_bytes, gas_limit) = match self {
+            Self::Script(script) => (script.metered_bytes_size(), script.limit()),
+            Self::Create(create) => (create.metered_bytes_size(), create.limit()),
+            Self::Mint(_) => return Ok(TransactionFee::default()),
+        };
+
+        TransactionFee::checked_from_values(params, metered_bytes as Word, gas_limit, gas_price)
+            .ok_or(CheckError::ArithmeticOverflow)
+    }
+
+    /// Serialized size of the transaction once signed.
+    ///
+    /// Every empty witness referenced by a signed input is accounted as holding a
+    /// [`Signature`], so fees can be estimated before the inputs are signed.
+    pub fn estimated_signed_size(&self) -> usize {
+        use field::{Inputs, Witnesses};
+
+        let (inputs, witnesses): (&[Input], &[Witness]) = match self {
+            Self::Script(script) => (script.inputs(), script.witnesses()),
+            Self::Create(create) => (create.inputs(), create.witnesses()),
+            Self::Mint(_) => return self.serialized_size(),
+        };
+
+        let mut indexes = inputs
+            .iter()
+            .filter_map(|input| match input {
+                Input::CoinSigned { witness_index, .. }
+                | Input::MessageSigned { witness_index, .. } => Some(*witness_index as usize),
+                _ => None,
+            })
+            .filter(|index| {
+                witnesses
+                    .get(*index)
+                    .map(|witness| witness.as_ref().is_empty())
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<usize>>();
+
+        indexes.sort_unstable();
+        indexes.dedup();
+
+        self.serialized_size() + indexes.len() * Signature::LEN
+    }
+
+    /// Estimated fee of the transaction, metering its [`Self::estimated_signed_size`] instead
+    /// of [`Chargeable::metered_bytes_size`].
+    ///
+    /// The witnesses, including the signatures yet to be added, are charged as regular bytes.
+    /// It is an estimate for fee models that may meter the witnesses, not a bound on the fee
+    /// charged by the consensus rules.
+    pub fn fee_with_witness_allowance(
+        &self,
+        params: &ConsensusParameters,
+    ) -> Result<TransactionFee, CheckError> {
+        let (gas_limit, gas_price) = match self {
+            Self::Script(script) => (script.limit(), script.price()),
+            Self::Create(create) => (create.limit(), create.price()),
+            Self::Mint(_) => return Ok(TransactionFee::default()),
+        };
+
+        let metered_bytes = self.estimated_signed_size() as Word;
+
+        TransactionFee::checked_from_values(params, metered_bytes, gas_limit, gas_price)
+            .ok_or(CheckError::ArithmeticOverflow)
+    }
+
+    /// Serialized size of the transaction, failing instead of overflowing.
+    ///
+    /// [`SizedBytes::serialized_size`] sums the sizes of the inputs, outputs and witnesses
+    /// unchecked, which is fine for well-formed transactions. Untrusted transactions should be
+    /// measured with this method instead.
+    pub fn try_serialized_size(&self) -> Result<usize, CheckError> {
+        use field::{Inputs, Outputs, Witnesses};
+
+        let size = match self {
+            Self::Script(script) => checked_size_sum(
+                script.inputs_offset(),
+                script
+                    .inputs()
+                    .iter()
+                    .map(SizedBytes::serialized_size)
+                    .chain(script.outputs().iter().map(SizedBytes::serialized_size))
+                    .chain(script.witnesses().iter().map(SizedBytes::serialized_size)),
+            ),
+            Self::Create(create) => checked_size_sum(
+                create.inputs_offset(),
+                create
+                    .inputs()
+                    .iter()
+                    .map(SizedBytes::serialized_size)
+                    .chain(create.outputs().iter().map(SizedBytes::serialized_size))
+                    .chain(create.witnesses().iter().map(SizedBytes::serialized_size)),
+            ),
+            Self::Mint(mint) => checked_size_sum(
+                mint.outputs_offset(),
+                mint.outputs().iter().map(SizedBytes::serialized_size),
+            ),
+        };
+
+        size.ok_or(CheckError::ArithmeticOverflow)
+    }
+
+    /// Serialized size of the witnesses of the transaction.
+    ///
+    /// Complements [`Chargeable::metered_bytes_size`], which excludes the witnesses.
+    pub fn witnesses_size(&self) -> usize {
+        use field::Witnesses;
+
+        match self {
+            Self::Script(script) => script.serialized_size() - script.witnesses_offset(),
+            Self::Create(create) => create.serialized_size() - create.witnesses_offset(),
+            Self::Mint(_) => 0,
+        }
+    }
+
+    /// Every input shared by several inputs of the transaction.
+    ///
+    /// Unlike [`Checkable::check`], which stops at the first duplicate, all the conflicts are
+    /// collected, coin UTXOs first, then contracts, then messages.
+    pub fn self_conflicts(&self) -> Vec<Conflict> {
+        use field::Inputs;
+
+        let inputs: &[Input] = match self {
+            Self::Script(script) => script.inputs(),
+            Self::Create(create) => create.inputs(),
+            Self::Mint(_) => &[],
+        };
+
+        let utxo_ids = inputs
+            .iter()
+            .filter_map(|i| i.is_coin().then(|| i.utxo_id()).flatten());
+        let contract_ids = inputs.iter().filter_map(Input::contract_id);
+        let message_ids = inputs.iter().filter_map(Input::message_id);
+
+        checkable::duplicates(utxo_ids)
+            .into_iter()
+            .map(|utxo_id| Conflict::UtxoId(*utxo_id))
+            .chain(
+                checkable::duplicates(contract_ids)
+                    .into_iter()
+                    .map(|contract_id| Conflict::ContractId(*contract_id)),
+            )
+            .chain(
+                checkable::duplicates(message_ids)
+                    .into_iter()
+                    .map(|message_id| Conflict::MessageId(*message_id)),
+            )
+            .collect()
+    }
+
+    /// Sort the inputs, outputs and witnesses so logically identical transactions share the
+    /// same id regardless of the order they were built in.
+    ///
+    /// Inputs and outputs are sorted by their serialized bytes, ignoring the witness indexes of
+    /// the inputs. Witnesses follow the order of the inputs that reference them; the others,
+    /// such as the bytecode of `Create`, come last, sorted by their bytes. The witness indexes
+    /// of the inputs, the bytecode witness index of `Create` and the input indexes of contract
+    /// outputs are remapped to the new positions.
+    ///
+    /// This changes the id of the transaction, so it must be called before signing. It must not
+    /// be used for scripts or predicates that address inputs, outputs or witnesses by index.
+    #[cfg(feature = "std")]
+    pub fn normalize(&mut self) {
+        use field::{BytecodeWitnessIndex, Outputs};
+
+        match self {
+            Self::Script(script) => {
+                normalize_executable(script);
+            }
+            Self::Create(create) => {
+                let witnesses = normalize_executable(create);
+                remap_index(create.bytecode_witness_index_mut(), &witnesses);
+            }
+            Self::Mint(mint) => {
+                sort_canonically(mint.outputs_mut());
+            }
+        }
+    }
+
+    /// Remove and return all the witnesses of the transaction, so they can be signed offline
+    /// and attached back later.
+    ///
+    /// The bytecode of `Create` transactions is one of their witnesses, so it fails with
+    /// [`CheckError::TransactionCreateBytecodeWitnessIndex`] instead of removing it. `Mint`
+    /// transactions have no witnesses.
+    pub fn take_witnesses(&mut self) -> Result<Vec<Witness>, CheckError> {
+        use field::{BytecodeWitnessIndex, Witnesses};
+
+        match self {
+            Self::Script(script) => Ok(core::mem::take(script.witnesses_mut())),
+            Self::Create(create)
+                if (*create.bytecode_witness_index() as usize) < create.witnesses().len() =>
+            {
+                Err(CheckError::TransactionCreateBytecodeWitnessIndex)
+            }
+            Self::Create(create) => Ok(core::mem::take(create.witnesses_mut())),
+            Self::Mint(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Shrink the capacity of every buffer of the transaction to its length.
+    ///
+    /// Decoding and building may leave spare capacity in the buffers, which is wasted memory
+    /// for transactions kept around for long, e.g. in a mempool.
+    pub fn compact(&mut self) {
+        use field::{Outputs, Script as ScriptField, ScriptData, StorageSlots};
+
+        match self {
+            Self::Script(script) => {
+                script.script_mut().shrink_to_fit();
+                script.script_data_mut().shrink_to_fit();
+                compact_executable(script);
+            }
+            Self::Create(create) => {
+                create.storage_slots_mut().shrink_to_fit();
+                compact_executable(create);
+            }
+            Self::Mint(mint) => mint.outputs_mut().shrink_to_fit(),
+        }
+    }
+
+    /// Byte offset of the first output of a serialized `Mint` transaction.
+    ///
+    /// Returns `None` if the transaction isn't a `Mint`.
+    pub fn mint_outputs_offset(&self) -> Option<usize> {
+        use field::Outputs;
+
+        self.as_mint().map(Outputs::outputs_offset)
+    }
+
+    /// Byte offset of the gas price in the serialized transaction, so it can be replaced in
+    /// place.
+    ///
+    /// Returns `None` for `Mint` transactions, which have no gas price.
+    pub fn gas_price_byte_offset(&self) -> Option<usize> {
+        use field::GasPrice;
+
+        match self {
+            Self::Script(script) => Some(script.gas_price_offset()),
+            Self::Create(create) => Some(create.gas_price_offset()),
+            Self::Mint(_) => None,
+        }
+    }
+
+    /// Byte offset of the `Input` at `idx` in the serialized transaction, if any.
+    ///
+    /// `Mint` transactions have no inputs, so it is always `None` for them.
+    pub fn input_offset(&self, idx: usize) -> Option<usize> {
+        use field::Inputs;
+
+        match self {
+            Self::Script(script) => script.inputs_offset_at(idx),
+            Self::Create(create) => create.inputs_offset_at(idx),
+            Self::Mint(_) => None,
+        }
+    }
+
+    /// Byte offset of the `Output` at `idx` in the serialized transaction, if any.
+    pub fn output_offset(&self, idx: usize) -> Option<usize> {
+        use field::Outputs;
+
+        match self {
+            Self::Script(script) => script.outputs_offset_at(idx),
+            Self::Create(create) => create.outputs_offset_at(idx),
+            Self::Mint(mint) => mint.outputs_offset_at(idx),
+        }
+    }
+
+    /// Byte offset of the `Witness` at `idx` in the serialized transaction, if any.
+    ///
+    /// `Mint` transactions have no witnesses, so it is always `None` for them.
+    pub fn witness_offset(&self, idx: usize) -> Option<usize> {
+        use field::Witnesses;
+
+        match self {
+            Self::Script(script) => script.witnesses_offset_at(idx),
+            Self::Create(create) => create.witnesses_offset_at(idx),
+            Self::Mint(_) => None,
+        }
+    }
+
+    /// Whether the transaction has an input at `index`.
+    pub fn has_input(&self, index: usize) -> bool {
+        use field::Inputs;
+
+        match self {
+            Self::Script(script) => index < script.inputs().len(),
+            Self::Create(create) => index < create.inputs().len(),
+            Self::Mint(_) => false,
+        }
+    }
+
+    /// Whether the transaction has an output at `index`.
+    pub fn has_output(&self, index: usize) -> bool {
+        use field::Outputs;
+
+        match self {
+            Self::Script(script) => index < script.outputs().len(),
+            Self::Create(create) => index < create.outputs().len(),
+            Self::Mint(mint) => index < mint.outputs().len(),
+        }
+    }
+
+    /// Whether the transaction has a witness at `index`.
+    pub fn has_witness(&self, index: usize) -> bool {
+        use field::Witnesses;
+
+        match self {
+            Self::Script(script) => index < script.witnesses().len(),
+            Self::Create(create) => index < create.witnesses().len(),
+            Self::Mint(_) => false,
+        }
+    }
+}
+
+impl Transaction {
+    /// Maximum fee of the transaction; `Mint` transactions don't pay fees.
+    fn checked_fee(&self, params: &ConsensusParameters) -> Result<TransactionFee, CheckError> {
+        match self {
+            Self::Script(script) => TransactionFee::checked_from_tx(params, script),
+            Self::Create(create) => TransactionFee::checked_from_tx(params, create),
+            Self::Mint(_) => Some(TransactionFee::default()),
+        }
+        .ok_or(CheckError::ArithmeticOverflow)
+    }
+}
+
+/// Shrink the inputs, outputs and witnesses of `tx`, and their buffers.
+fn compact_executable<T: Executable>(tx: &mut T) {
+    tx.inputs_mut().iter_mut().for_each(Input::shrink_to_fit);
+    tx.inputs_mut().shrink_to_fit();
+    tx.outputs_mut().shrink_to_fit();
+    tx.witnesses_mut()
+        .iter_mut()
+        .for_each(Witness::shrink_to_fit);
+    tx.witnesses_mut().shrink_to_fit();
+}
+
+/// Normalize the inputs, outputs and witnesses of `tx`, returning the new position of every
+/// witness.
+#[cfg(feature = "std")]
+fn normalize_executable<T: Executable>(tx: &mut T) -> Vec<usize> {
+    use fuel_types::bytes::SerializableVec;
+
+    let witnesses = core::mem::take(tx.witnesses_mut());
+
+    // The witness indexes depend on the order of the witnesses, so they only break ties
+    let inputs = sort_by_key(tx.inputs_mut(), |input| {
+        let witness_index = input.witness_index();
+        let witness = witness_index
+            .and_then(|index| witnesses.get(index as usize))
+            .map(|witness| witness.as_ref().to_vec());
+
+        let mut key = input.clone();
+        set_witness_index(&mut key, 0);
+
+        (key.to_bytes(), witness, witness_index)
+    });
+
+    tx.outputs_mut().iter_mut().for_each(|output| {
+        if let Output::Contract { input_index, .. } = output {
+            remap_index(input_index, &inputs);
+        }
+    });
+
+    sort_canonically(tx.outputs_mut());
+
+    // Referenced witnesses take the order of the sorted inputs, the others are sorted last
+    let mut order = tx
+        .inputs()
+        .iter()
+        .filter_map(Input::witness_index)
+        .map(|index| index as usize)
+        .filter(|index| *index < witnesses.len())
+        .unique()
+        .collect_vec();
+
+    let mut unreferenced = (0..witnesses.len())
+        .filter(|index| !order.contains(index))
+        .collect_vec();
+    unreferenced.sort_by(|a, b| witnesses[*a].as_ref().cmp(witnesses[*b].as_ref()));
+    order.extend(unreferenced);
+
+    let mut positions = vec![0; witnesses.len()];
+    order
+        .iter()
+        .enumerate()
+        .for_each(|(new, old)| positions[*old] = new);
+
+    tx.inputs_mut().iter_mut().for_each(|input| {
+        if let Some(mut witness_index) = input.witness_index() {
+            remap_index(&mut witness_index, &positions);
+            set_witness_index(input, witness_index);
+        }
+    });
+
+    let mut witnesses = witnesses.into_iter().map(Some).collect_vec();
+    *tx.witnesses_mut() = order
+        .into_iter()
+        .filter_map(|old| witnesses[old].take())
+        .collect();
+
+    positions
+}
+
+/// Sort `items` by their serialized bytes, returning the new position of every item.
+#[cfg(feature = "std")]
+fn sort_canonically<T: fuel_types::bytes::SerializableVec>(items: &mut Vec<T>) -> Vec<usize> {
+    sort_by_key(items, T::to_bytes)
+}
+
+/// Sort `items` by `key`, returning the new position of every item.
+#[cfg(feature = "std")]
+fn sort_by_key<T, K, F>(items: &mut Vec<T>, mut key: F) -> Vec<usize>
+where
+    K: Ord,
+    F: FnMut(&mut T) -> K,
+{
+    let mut keyed = core::mem::take(items)
+        .into_iter()
+        .enumerate()
+        .map(|(position, mut item)| (key(&mut item), position, item))
+        .collect_vec();
+
+    keyed.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut positions = vec![0; keyed.len()];
+    *items = keyed
+        .into_iter()
+        .enumerate()
+        .map(|(new, (_, old, item))| {
+            positions[old] = new;
+            item
+        })
+        .collect();
+
+    positions
+}
+
+/// Replace the witness index of a signed input; other inputs are left untouched.
+#[cfg(feature = "std")]
+fn set_witness_index(input: &mut Input, index: u8) {
+    if let Input::CoinSigned { witness_index, .. } | Input::MessageSigned { witness_index, .. } =
+        input
+    {
+        *witness_index = index;
+    }
+}
+
+/// Point `index` to its new position; out of bounds indexes are left untouched.
+#[cfg(feature = "std")]
+fn remap_index(index: &mut u8, positions: &[usize]) {
+    if let Some(position) = positions.get(*index as usize) {
+        *index = *position as u8;
+    }
+}
+
+/// Gas consumed by the signature verification and the I/O of a transaction.
+pub(crate) fn intrinsic_gas(
+    inputs: &[Input],
+    outputs: &[Output],
+    params: &ConsensusParameters,
+) -> Word {
+    let costs = &params.gas_costs;
+    let signatures = inputs.iter().filter(|i| i.requires_witness()).count() as Word;
+
+    signatures
+        .saturating_mul(costs.signature_verification)
+        .saturating_add((inputs.len() as Word).saturating_mul(costs.input))
+        .saturating_add((outputs.len() as Word).saturating_mul(costs.output))
 }
 
 pub trait Executable: field::Inputs + field::Outputs + field::Witnesses {
     /// Returns the assets' ids used in the inputs in the order of inputs.
     fn input_asset_ids(&self) -> IntoIter<&AssetId> {
+        self.input_asset_ids_with_base(&AssetId::BASE)
+    }
+
+    /// Returns unique assets' ids used in the inputs.
+    fn input_asset_ids_unique(&self) -> IntoIter<&AssetId> {
+        self.input_asset_ids_unique_with_base(&AssetId::BASE)
+    }
+
+    /// Returns the assets' ids used in the inputs in the order of inputs.
+    ///
+    /// Message inputs are accounted as `base_asset_id`.
+    fn input_asset_ids_with_base<'a>(
+        &'a self,
+        base_asset_id: &'a AssetId,
+    ) -> IntoIter<&'a AssetId> {
         self.inputs()
             .iter()
             .filter_map(|input| match input {
                 Input::CoinPredicate { asset_id, .. } | Input::CoinSigned { asset_id, .. } => {
                     Some(asset_id)
                 }
-                Input::MessagePredicate { .. } | Input::MessageSigned { .. } => {
-                    Some(&AssetId::BASE)
-                }
+                Input::MessagePredicate { .. } | Input::MessageSigned { .. } => Some(base_asset_id),
                 _ => None,
             })
             .collect_vec()
             .into_iter()
     }
 
-    /// Returns unique assets' ids used in the inputs.
-    fn input_asset_ids_unique(&self) -> IntoIter<&AssetId> {
-        let asset_ids = self.input_asset_ids();
+    /// Returns unique assets' ids used in the inputs; message inputs are accounted as
+    /// `base_asset_id`.
+    fn input_asset_ids_unique_with_base<'a>(
+        &'a self,
+        base_asset_id: &'a AssetId,
+    ) -> IntoIter<&'a AssetId> {
+        let asset_ids = self.input_asset_ids_with_base(base_asset_id);
+
+        #[cfg(feature = "std")]
+        let asset_ids = asset_ids.unique();
+
+        #[cfg(not(feature = "std"))]
+        let asset_ids = asset_ids.sorted().dedup();
+
+        asset_ids.collect_vec().into_iter()
+    }
+
+    /// Returns the assets' ids used in the coin, change and variable outputs in the order of
+    /// outputs.
+    fn output_asset_ids(&self) -> IntoIter<&AssetId> {
+        self.outputs()
+            .iter()
+            .filter_map(|output| match output {
+                Output::Coin { asset_id, .. }
+                | Output::Change { asset_id, .. }
+                | Output::Variable { asset_id, .. } => Some(asset_id),
+                _ => None,
+            })
+            .collect_vec()
+            .into_iter()
+    }
+
+    /// Returns unique assets' ids used in the outputs.
+    fn output_asset_ids_unique(&self) -> IntoIter<&AssetId> {
+        let asset_ids = self.output_asset_ids();
 
         #[cfg(feature = "std")]
         let asset_ids = asset_ids.unique();
@@ -254,6 +1179,8 @@ pub trait Executable: field::Inputs + field::Outputs + field::Witnesses {
     /// Checks that all owners of inputs in the predicates are valid.
     #[cfg(feature = "std")]
     fn check_predicate_owners(&self) -> bool {
+        let mut owners = checkable::PredicateOwners::default();
+
         self.inputs()
             .iter()
             .filter_map(|i| match i {
@@ -267,9 +1194,7 @@ pub trait Executable: field::Inputs + field::Outputs + field::Witnesses {
                 } => Some((recipient, predicate)),
                 _ => None,
             })
-            .fold(true, |result, (owner, predicate)| {
-                result && Input::is_predicate_owner_valid(owner, predicate)
-            })
+            .all(|(owner, predicate)| owner == &owners.get(predicate))
     }
 
     /// Append a new unsigned coin input to the transaction.
@@ -368,6 +1293,16 @@ pub trait Executable: field::Inputs + field::Outputs + field::Witnesses {
 
 impl<T: field::Inputs + field::Outputs + field::Witnesses> Executable for T {}
 
+/// Add `sizes` to `offset`, returning `None` on overflow.
+fn checked_size_sum<I>(offset: usize, sizes: I) -> Option<usize>
+where
+    I: IntoIterator<Item = usize>,
+{
+    sizes
+        .into_iter()
+        .try_fold(offset, |total, size| total.checked_add(size))
+}
+
 impl SizedBytes for Transaction {
     fn serialized_size(&self) -> usize {
         match self {
@@ -547,6 +1482,33 @@ pub mod field {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Receipt;
+
+    #[test]
+    fn variants_are_listed() {
+        // `Input` and `Output` are non-exhaustive, so `TransactionFactory` of the test helpers
+        // can't match them exhaustively. Adding a variant breaks this match: list it in the
+        // factory as well.
+        let input = |input: Input| match input {
+            Input::CoinSigned { .. } => (),
+            Input::CoinPredicate { .. } => (),
+            Input::Contract { .. } => (),
+            Input::MessageSigned { .. } => (),
+            Input::MessagePredicate { .. } => (),
+        };
+
+        let output = |output: Output| match output {
+            Output::Coin { .. } => (),
+            Output::Contract { .. } => (),
+            Output::Message { .. } => (),
+            Output::Change { .. } => (),
+            Output::Variable { .. } => (),
+            Output::ContractCreated { .. } => (),
+        };
+
+        input(Input::default());
+        output(Output::default());
+    }
 
     #[test]
     fn metered_data_excludes_witnesses() {
@@ -596,4 +1558,1133 @@ mod tests {
             create_with_no_witnesses.metered_bytes_size()
         );
     }
+
+    #[test]
+    fn witnesses_size_sums_witnesses() {
+        let witnesses: Vec<Witness> = vec![vec![0xfa; 64].into(), vec![0xfb; 3].into()];
+        let witnesses_size = witnesses.iter().map(|w| w.serialized_size()).sum::<usize>();
+
+        let script: Transaction =
+            Transaction::script(0, 0, 0, vec![], vec![], vec![], vec![], witnesses.clone()).into();
+        assert_eq!(witnesses_size, script.witnesses_size());
+
+        let create: Transaction = Transaction::create(
+            0,
+            0,
+            0,
+            0,
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            witnesses,
+        )
+        .into();
+        assert_eq!(witnesses_size, create.witnesses_size());
+
+        let mint: Transaction = Transaction::mint(Default::default(), vec![]).into();
+        assert_eq!(0, mint.witnesses_size());
+    }
+
+    #[test]
+    fn take_witnesses_round_trip() {
+        use crate::field::Witnesses;
+
+        let witnesses: Vec<Witness> = vec![vec![0xfa; 64].into(), vec![0xfb; 64].into()];
+        let mut tx: Transaction =
+            Transaction::script(0, 0, 0, vec![], vec![], vec![], vec![], witnesses.clone()).into();
+        let expected = tx.clone();
+
+        assert_eq!(Ok(witnesses), tx.take_witnesses());
+        assert_eq!(Ok(vec![]), tx.take_witnesses());
+
+        let script = tx.as_script_mut().expect("script tx");
+        assert!(script.witnesses().is_empty());
+        *script.witnesses_mut() = expected.as_script().unwrap().witnesses().clone();
+        assert_eq!(expected, tx);
+
+        let mut create: Transaction = Transaction::create(
+            0,
+            0,
+            0,
+            0,
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            vec![vec![0xfc; 64].into()],
+        )
+        .into();
+        let expected = create.clone();
+
+        assert_eq!(
+            Err(CheckError::TransactionCreateBytecodeWitnessIndex),
+            create.take_witnesses()
+        );
+        assert_eq!(expected, create);
+
+        let mut mint: Transaction = Transaction::mint(Default::default(), vec![]).into();
+        assert_eq!(Ok(vec![]), mint.take_witnesses());
+    }
+
+    #[test]
+    fn compact_shrinks_buffers_to_length() {
+        use field::{Inputs, Outputs, Script as ScriptField, ScriptData, Witnesses};
+
+        fn spare(len: usize) -> Vec<u8> {
+            let mut data = Vec::with_capacity(len * 4);
+            data.resize(len, 0xfa);
+            data
+        }
+
+        let mut inputs = Vec::with_capacity(8);
+        inputs.push(Input::coin_predicate(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            spare(16),
+            spare(3),
+        ));
+        inputs.push(Input::message_signed(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+            0,
+            spare(5),
+        ));
+
+        let mut outputs = Vec::with_capacity(8);
+        outputs.push(Output::coin(Default::default(), 0, Default::default()));
+
+        let mut witnesses = Vec::with_capacity(8);
+        witnesses.push(Witness::from(spare(64)));
+
+        let mut tx: Transaction =
+            Transaction::script(0, 0, 0, spare(16), spare(7), inputs, outputs, witnesses).into();
+        let expected = tx.clone();
+
+        tx.compact();
+        assert_eq!(expected, tx);
+
+        let script = tx.as_script().expect("script tx");
+        assert_eq!(script.script().len(), script.script().capacity());
+        assert_eq!(script.script_data().len(), script.script_data().capacity());
+        assert_eq!(script.inputs().len(), script.inputs().capacity());
+        assert_eq!(script.outputs().len(), script.outputs().capacity());
+        assert_eq!(script.witnesses().len(), script.witnesses().capacity());
+
+        let witness = script.witnesses()[0].as_vec();
+        assert_eq!(witness.len(), witness.capacity());
+
+        match &script.inputs()[0] {
+            Input::CoinPredicate {
+                predicate,
+                predicate_data,
+                ..
+            } => {
+                assert_eq!(predicate.len(), predicate.capacity());
+                assert_eq!(predicate_data.len(), predicate_data.capacity());
+            }
+            _ => unreachable!(),
+        }
+
+        match &script.inputs()[1] {
+            Input::MessageSigned { data, .. } => assert_eq!(data.len(), data.capacity()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn try_serialized_size_detects_overflow() {
+        let script: Transaction = Transaction::script(
+            0,
+            0,
+            0,
+            vec![0xfa; 32],
+            vec![],
+            vec![Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )],
+            vec![Output::coin(Default::default(), 0, Default::default())],
+            vec![vec![0xfb; 64].into()],
+        )
+        .into();
+        assert_eq!(Ok(script.serialized_size()), script.try_serialized_size());
+
+        let mint: Transaction = Transaction::mint(
+            Default::default(),
+            vec![Output::coin(Default::default(), 0, Default::default())],
+        )
+        .into();
+        assert_eq!(Ok(mint.serialized_size()), mint.try_serialized_size());
+
+        // Transactions large enough to overflow can't be allocated, so stub the element sizes
+        let huge_inputs = [usize::MAX / 4; 5];
+        assert_eq!(None, checked_size_sum(INPUT_CONTRACT_SIZE, huge_inputs));
+        assert_eq!(Some(usize::MAX), checked_size_sum(usize::MAX - 2, [1, 1]));
+    }
+
+    #[test]
+    fn estimated_signed_size_matches_signed_tx() {
+        use crate::{Signable, TransactionBuilder};
+        use fuel_crypto::SecretKey;
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let a = SecretKey::random(rng);
+        let b = SecretKey::random(rng);
+
+        let unsigned = TransactionBuilder::script(vec![0xfa; 16], vec![])
+            .add_unsigned_coin_input(a, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+            .add_unsigned_coin_input(a, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+            .add_unsigned_message_input(b, rng.gen(), rng.gen(), rng.gen(), vec![0xfb; 10])
+            .finalize_without_signature();
+
+        let mut signed = unsigned.clone();
+        signed.sign_inputs(&a);
+        signed.sign_inputs(&b);
+
+        let unsigned = Transaction::from(unsigned);
+        let signed = Transaction::from(signed);
+
+        assert!(unsigned.serialized_size() < signed.serialized_size());
+        assert_eq!(signed.serialized_size(), unsigned.estimated_signed_size());
+        assert_eq!(signed.serialized_size(), signed.estimated_signed_size());
+    }
+
+    #[test]
+    fn fee_with_witness_allowance_includes_allowance() {
+        use crate::TransactionBuilder;
+        use fuel_crypto::SecretKey;
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let params = ConsensusParameters::DEFAULT;
+        let secret = SecretKey::random(rng);
+
+        let tx: Transaction = TransactionBuilder::script(vec![0xfa; 16], vec![])
+            .gas_price(1_000)
+            .gas_limit(1_000)
+            .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+            .add_unsigned_message_input(secret, rng.gen(), rng.gen(), rng.gen(), vec![0xfb; 10])
+            .finalize_without_signature()
+            .into();
+
+        let metered = tx
+            .fee_at_price(1_000, &params)
+            .expect("failed to compute fee");
+        let allowance = tx
+            .fee_with_witness_allowance(&params)
+            .expect("failed to compute fee");
+
+        assert!(allowance.min_gas() > metered.min_gas());
+        assert!(allowance.total() >= metered.total());
+        assert_eq!(
+            allowance.max_gas() - allowance.min_gas(),
+            metered.max_gas() - metered.min_gas()
+        );
+
+        let mint = Transaction::from(Transaction::mint(Default::default(), vec![]));
+        assert_eq!(
+            Ok(TransactionFee::default()),
+            mint.fee_with_witness_allowance(&params)
+        );
+    }
+
+    #[test]
+    fn total_input_value_detects_overflow() {
+        let asset_id = AssetId::from([0xfa; 32]);
+        let coin = |amount| {
+            Input::coin_signed(
+                Default::default(),
+                Default::default(),
+                amount,
+                asset_id,
+                Default::default(),
+                0,
+                0,
+            )
+        };
+        let message = Input::message_signed(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            5,
+            0,
+            0,
+            vec![],
+        );
+
+        let script = |inputs| {
+            Transaction::from(Transaction::script(
+                0,
+                0,
+                0,
+                vec![],
+                vec![],
+                inputs,
+                vec![],
+                vec![],
+            ))
+        };
+
+        let tx = script(vec![coin(10), message.clone(), coin(20)]);
+        assert_eq!(Ok(30), tx.total_input_value(&asset_id, &AssetId::BASE));
+        assert_eq!(Ok(5), tx.total_input_value(&AssetId::BASE, &AssetId::BASE));
+        assert_eq!(Ok(35), tx.total_input_value(&asset_id, &asset_id));
+
+        let tx = script(vec![coin(Word::MAX), coin(1)]);
+        assert_eq!(
+            Err(CheckError::ArithmeticOverflow),
+            tx.total_input_value(&asset_id, &AssetId::BASE)
+        );
+    }
+
+    #[test]
+    fn asset_flows_accounts_inputs_and_outputs_per_asset() {
+        let a = AssetId::from([0xaa; 32]);
+        let b = AssetId::from([0xbb; 32]);
+
+        let coin = |amount, asset_id| {
+            Input::coin_signed(
+                Default::default(),
+                Default::default(),
+                amount,
+                asset_id,
+                Default::default(),
+                0,
+                0,
+            )
+        };
+        let message = Input::message_signed(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            7,
+            0,
+            0,
+            vec![],
+        );
+
+        let tx = Transaction::from(Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![coin(100, a), coin(50, b), message, coin(20, a)],
+            vec![
+                Output::coin(Default::default(), 60, a),
+                Output::change(Default::default(), 0, a),
+                Output::variable(Default::default(), 5, b),
+                Output::coin(Default::default(), 30, b),
+                Output::message(Default::default(), 3),
+            ],
+            vec![],
+        ));
+
+        let flows = tx.asset_flows(&a);
+
+        assert_eq!(2, flows.len());
+        assert_eq!((127, 60), flows[&a]);
+        assert_eq!((50, 35), flows[&b]);
+    }
+
+    #[test]
+    fn minimal_valid_passes_checks_without_signatures() {
+        let params = ConsensusParameters::DEFAULT
+            .with_max_gas_per_tx(10_000)
+            .with_base_asset_id([0xaa; 32].into());
+
+        for params in [ConsensusParameters::DEFAULT, params] {
+            let tx = Transaction::minimal_valid(&params);
+
+            tx.check_without_signatures(0, &params)
+                .expect("minimal transaction should be valid");
+            assert_eq!(
+                Ok(1),
+                tx.total_input_value(&params.base_asset_id, &params.base_asset_id)
+            );
+        }
+    }
+
+    #[test]
+    fn created_utxos_skips_unspendable_outputs() {
+        let txid = Bytes32::from([0xaa; 32]);
+        let asset_id = AssetId::from([0xbb; 32]);
+
+        let outputs = vec![
+            Output::contract(0, Default::default(), Default::default()),
+            Output::coin(Default::default(), 10, asset_id),
+            Output::message(Default::default(), 3),
+            Output::change(Default::default(), 0, asset_id),
+            Output::contract_created(Default::default(), Default::default()),
+            Output::variable(Default::default(), 0, asset_id),
+        ];
+
+        let tx = Transaction::from(Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![],
+            outputs.clone(),
+            vec![],
+        ));
+
+        let utxos = tx.created_utxos(&txid);
+
+        assert_eq!(3, utxos.len());
+        [1, 3, 5]
+            .iter()
+            .zip(utxos.iter())
+            .for_each(|(index, (utxo_id, output))| {
+                assert_eq!(&txid, utxo_id.tx_id());
+                assert_eq!(*index as u8, utxo_id.output_index());
+                assert_eq!(&outputs[*index], *output);
+            });
+
+        let mint = Transaction::from(Transaction::mint(Default::default(), outputs));
+        assert_eq!(utxos, mint.created_utxos(&txid));
+    }
+
+    #[test]
+    fn is_more_efficient_than_requires_same_transfers() {
+        // A unit factor so that the cost of a few bytes isn't rounded away
+        let params = ConsensusParameters::DEFAULT.with_gas_price_factor(1);
+        let base = params.base_asset_id;
+        let to = Address::from([0xaa; 32]);
+
+        let coin = |amount| {
+            Input::coin_signed(
+                Default::default(),
+                Default::default(),
+                amount,
+                base,
+                Default::default(),
+                0,
+                0,
+            )
+        };
+        let script = |inputs, outputs| -> Transaction {
+            Transaction::script(1, 1_000, 0, vec![], vec![], inputs, outputs, vec![]).into()
+        };
+
+        let outputs = vec![Output::coin(to, 60, base), Output::change(to, 0, base)];
+
+        let lean = script(vec![coin(100)], outputs.clone());
+        // The extra input isn't needed for the transfers, but is charged for its bytes
+        let redundant = script(vec![coin(100), coin(10)], outputs);
+
+        assert!(lean.is_more_efficient_than(&redundant, &params));
+        assert!(!redundant.is_more_efficient_than(&lean, &params));
+        assert!(!lean.is_more_efficient_than(&lean, &params));
+
+        let other_transfer = script(vec![coin(100)], vec![Output::coin(to, 50, base)]);
+        assert!(!other_transfer.is_more_efficient_than(&redundant, &params));
+    }
+
+    #[test]
+    fn fee_per_byte_grows_with_gas_price() {
+        let params = ConsensusParameters::DEFAULT;
+        let script = |gas_price| {
+            Transaction::from(Transaction::script(
+                gas_price,
+                1_000_000,
+                0,
+                vec![0xfa; 32],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+            ))
+        };
+
+        let cheap = script(1_000).fee_per_byte(&params).unwrap();
+        let expensive = script(2_000).fee_per_byte(&params).unwrap();
+
+        assert!(cheap > 0.0);
+        assert!(expensive > cheap);
+
+        let mint = Transaction::mint(Default::default(), vec![]);
+        assert_eq!(Ok(0.0), Transaction::from(mint).fee_per_byte(&params));
+
+        let overflow = script(Word::MAX).fee_per_byte(&params);
+        assert_eq!(Err(CheckError::ArithmeticOverflow), overflow);
+    }
+
+    #[test]
+    fn intrinsic_gas_grows_with_inputs() {
+        let params = ConsensusParameters::DEFAULT.with_gas_costs(GasCosts {
+            signature_verification: 1_000,
+            input: 10,
+            output: 10,
+            ..GasCosts::DEFAULT
+        });
+        let input = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+        );
+
+        let script = |inputs| {
+            Transaction::from(Transaction::script(
+                0,
+                0,
+                0,
+                vec![],
+                vec![],
+                inputs,
+                vec![],
+                vec![],
+            ))
+        };
+
+        let empty = script(vec![]).intrinsic_gas(&params);
+        let one = script(vec![input.clone()]).intrinsic_gas(&params);
+        let two = script(vec![input.clone(), input]).intrinsic_gas(&params);
+
+        assert_eq!(0, empty);
+        assert!(empty < one);
+        assert!(one < two);
+
+        let costs = params.gas_costs;
+        assert_eq!(2 * (costs.signature_verification + costs.input), two);
+    }
+
+    #[test]
+    fn self_conflicts_lists_every_duplicate() {
+        let utxo_id = UtxoId::new([0xaa; 32].into(), 0);
+        let contract_id = [0xbb; 32].into();
+        let coin = Input::coin_signed(
+            utxo_id,
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+        );
+        let contract = Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        );
+        let other_contract = Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            [0xcc; 32].into(),
+        );
+
+        let tx = Transaction::from(Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![
+                contract.clone(),
+                coin.clone(),
+                other_contract,
+                contract,
+                coin,
+            ],
+            vec![],
+            vec![],
+        ));
+
+        assert_eq!(
+            vec![Conflict::UtxoId(utxo_id), Conflict::ContractId(contract_id)],
+            tx.self_conflicts()
+        );
+        assert!(Transaction::default().self_conflicts().is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn normalize_makes_id_independent_of_order() {
+        use crate::field::{Inputs, Outputs};
+        use crate::UniqueIdentifier;
+
+        let coin = |byte: u8, witness_index| {
+            Input::coin_signed(
+                UtxoId::new([byte; 32].into(), 0),
+                Default::default(),
+                byte as Word,
+                Default::default(),
+                Default::default(),
+                witness_index,
+                0,
+            )
+        };
+        let contract = Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            [0xcc; 32].into(),
+        );
+        let change = Output::change(Default::default(), 0, Default::default());
+        let witnesses: Vec<Witness> = vec![vec![0xaa; 64].into(), vec![0xbb; 64].into()];
+
+        let mut a = Transaction::from(Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![coin(0xaa, 0), contract.clone(), coin(0xbb, 1)],
+            vec![
+                change,
+                Output::contract(1, Default::default(), Default::default()),
+            ],
+            witnesses.clone(),
+        ));
+        let mut b = Transaction::from(Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![contract, coin(0xbb, 0), coin(0xaa, 1)],
+            vec![
+                Output::contract(0, Default::default(), Default::default()),
+                change,
+            ],
+            witnesses.into_iter().rev().collect(),
+        ));
+
+        assert_ne!(a.id(), b.id());
+
+        a.normalize();
+        b.normalize();
+
+        assert_eq!(a, b);
+        assert_eq!(a.id(), b.id());
+
+        let script = a.as_script().expect("script tx");
+        let contract_output = script
+            .outputs()
+            .iter()
+            .find_map(|output| match output {
+                Output::Contract { input_index, .. } => Some(*input_index as usize),
+                _ => None,
+            })
+            .expect("contract output");
+        assert!(script.inputs()[contract_output].contract_id().is_some());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn normalize_ignores_placeholder_witnesses_order() {
+        use crate::field::{Inputs, Witnesses};
+        use crate::UniqueIdentifier;
+
+        // Unsigned transactions carry identical empty witnesses, so only the inputs can order them
+        let coin = |owner: u8, witness_index| {
+            Input::coin_signed(
+                Default::default(),
+                [owner; 32].into(),
+                0,
+                Default::default(),
+                Default::default(),
+                witness_index,
+                0,
+            )
+        };
+        let script = |inputs| {
+            Transaction::from(Transaction::script(
+                0,
+                0,
+                0,
+                vec![],
+                vec![],
+                inputs,
+                vec![],
+                vec![Witness::default(), Witness::default()],
+            ))
+        };
+
+        let mut a = script(vec![coin(0xaa, 0), coin(0xbb, 1)]);
+        let mut b = script(vec![coin(0xbb, 0), coin(0xaa, 1)]);
+
+        assert_ne!(a.id(), b.id());
+
+        a.normalize();
+        b.normalize();
+
+        assert_eq!(a, b);
+        assert_eq!(a.id(), b.id());
+
+        let script = a.as_script().expect("script tx");
+        let witness_indexes = script
+            .inputs()
+            .iter()
+            .filter_map(Input::witness_index)
+            .collect_vec();
+        assert_eq!(vec![0, 1], witness_indexes);
+        assert_eq!(2, script.witnesses().len());
+    }
+
+    #[test]
+    fn total_block_fees_sums_max_fees() {
+        use crate::field::GasPrice;
+
+        let params = ConsensusParameters::DEFAULT;
+
+        let script = Transaction::script(
+            1_000,
+            1_000_000,
+            0,
+            vec![0xfa; 32],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+        let create = Transaction::create(
+            2_000,
+            100_000,
+            0,
+            0,
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            vec![vec![0xfb; 64].into()],
+        );
+        let mint = Transaction::mint(Default::default(), vec![]);
+
+        let expected = TransactionFee::checked_from_tx(&params, &script)
+            .unwrap()
+            .total()
+            + TransactionFee::checked_from_tx(&params, &create)
+                .unwrap()
+                .total();
+
+        let txs = [script.clone().into(), create.into(), mint.into()];
+        assert_eq!(Ok(expected), Transaction::total_block_fees(&txs, &params));
+        assert_eq!(Ok(0), Transaction::total_block_fees(&[], &params));
+
+        let mut expensive = script;
+        *expensive.gas_price_mut() = Word::MAX / 2;
+        let txs = [expensive.clone().into(), expensive.into()];
+        assert_eq!(
+            Err(CheckError::ArithmeticOverflow),
+            Transaction::total_block_fees(&txs, &params)
+        );
+    }
+
+    #[test]
+    fn fee_at_price_matches_fee_with_gas_price_set() {
+        use crate::field::GasPrice;
+
+        let params = ConsensusParameters::DEFAULT;
+        let mut script = Transaction::script(
+            1,
+            1_000_000,
+            0,
+            vec![0xfa; 32],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+        let tx = Transaction::from(script.clone());
+
+        *script.gas_price_mut() = 3_000;
+        let expected = TransactionFee::checked_from_tx(&params, &script).unwrap();
+
+        assert_eq!(Ok(expected), tx.fee_at_price(3_000, &params));
+        assert_eq!(Some(&1), tx.as_script().map(GasPrice::gas_price));
+
+        let overflow = tx.fee_at_price(Word::MAX, &params);
+        assert_eq!(Err(CheckError::ArithmeticOverflow), overflow);
+
+        let mint = Transaction::from(Transaction::mint(Default::default(), vec![]));
+        assert_eq!(
+            Ok(TransactionFee::default()),
+            mint.fee_at_price(3_000, &params)
+        );
+    }
+
+    #[test]
+    fn try_balance_change_is_checked() {
+        let asset_id = AssetId::from([0xfa; 32]);
+        let coin = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            100,
+            asset_id,
+            Default::default(),
+            0,
+            0,
+        );
+
+        let script = |outputs: Vec<Output>| {
+            Transaction::from(Transaction::script(
+                0,
+                0,
+                0,
+                vec![],
+                vec![],
+                vec![coin.clone()],
+                outputs,
+                vec![],
+            ))
+        };
+        let output = |amount| Output::coin(Default::default(), amount, asset_id);
+
+        // exact
+        let tx = script(vec![output(60), output(30)]);
+        assert_eq!(Ok(0), tx.try_balance_change(&asset_id, &AssetId::BASE, 10));
+
+        // surplus
+        let tx = script(vec![
+            output(60),
+            Output::coin(Default::default(), 50, AssetId::BASE),
+        ]);
+        assert_eq!(Ok(35), tx.try_balance_change(&asset_id, &AssetId::BASE, 5));
+
+        // deficit
+        let tx = script(vec![output(60), output(30)]);
+        assert_eq!(
+            Err(CheckError::InsufficientInputAmount {
+                asset: asset_id,
+                expected: 101,
+                provided: 100,
+            }),
+            tx.try_balance_change(&asset_id, &AssetId::BASE, 11)
+        );
+
+        let tx = script(vec![output(1)]);
+        assert_eq!(
+            Err(CheckError::ArithmeticOverflow),
+            tx.try_balance_change(&asset_id, &AssetId::BASE, Word::MAX)
+        );
+    }
+
+    #[test]
+    fn has_io_at_boundary_indices() {
+        let tx = Transaction::from(Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![Input::default(); 2],
+            vec![Output::default(); 3],
+            vec![Witness::default()],
+        ));
+
+        assert!(tx.has_input(0) && tx.has_input(1));
+        assert!(!tx.has_input(2));
+        assert!(tx.has_output(0) && tx.has_output(2));
+        assert!(!tx.has_output(3));
+        assert!(tx.has_witness(0));
+        assert!(!tx.has_witness(1));
+
+        let mint = Transaction::from(Transaction::mint(
+            Default::default(),
+            vec![Output::default()],
+        ));
+
+        assert!(!mint.has_input(0));
+        assert!(mint.has_output(0));
+        assert!(!mint.has_output(1));
+        assert!(!mint.has_witness(0));
+    }
+
+    #[test]
+    fn predicate_gas_requirements_lists_predicate_inputs() {
+        let params = ConsensusParameters::DEFAULT.with_gas_costs(GasCosts {
+            predicate_per_byte: 1,
+            ..GasCosts::DEFAULT
+        });
+
+        let predicate_input = |predicate: Vec<u8>| {
+            Input::coin_predicate_derive_owner(
+                Default::default(),
+                0,
+                Default::default(),
+                Default::default(),
+                0,
+                predicate,
+                vec![],
+            )
+        };
+
+        let short = predicate_input(vec![0xaa; 16]);
+        let long = predicate_input(vec![0xaa; 256]);
+        let signed = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+        );
+
+        let tx: Transaction = Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![short.clone(), signed, long.clone()],
+            vec![],
+            vec![],
+        )
+        .into();
+
+        let estimates = tx.predicate_gas_requirements(&params);
+        assert_eq!(
+            vec![
+                (0, short.estimate_predicate_gas(&params)),
+                (2, long.estimate_predicate_gas(&params)),
+            ],
+            estimates
+        );
+        assert!(estimates[0].1 < estimates[1].1);
+
+        let mint: Transaction = Transaction::mint(Default::default(), vec![]).into();
+        assert!(mint.predicate_gas_requirements(&params).is_empty());
+    }
+
+    #[test]
+    fn validate_input_at_checks_single_input() {
+        let params = ConsensusParameters::DEFAULT;
+
+        let signed = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+        );
+        let out_of_bounds = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            1,
+            0,
+        );
+
+        let tx: Transaction = Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![signed, out_of_bounds],
+            vec![],
+            vec![Witness::default()],
+        )
+        .into();
+
+        assert_eq!(Ok(()), tx.validate_input_at(0, &params));
+        assert_eq!(
+            Err(CheckError::InputWitnessIndexBounds { index: 1 }),
+            tx.validate_input_at(1, &params)
+        );
+        assert_eq!(
+            Err(CheckError::InputIndexBounds { index: 2 }),
+            tx.validate_input_at(2, &params)
+        );
+    }
+
+    #[test]
+    fn required_signers_lists_signed_owners() {
+        let a = Address::from([0xaa; 32]);
+        let b = Address::from([0xbb; 32]);
+
+        let coin = |owner| {
+            Input::coin_signed(
+                Default::default(),
+                owner,
+                0,
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+            )
+        };
+        let message = |recipient| {
+            Input::message_signed(
+                Default::default(),
+                Default::default(),
+                recipient,
+                0,
+                0,
+                1,
+                vec![],
+            )
+        };
+        let predicate = Input::coin_predicate_derive_owner(
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            vec![0xaa; 16],
+            vec![],
+        );
+
+        let tx: Transaction = Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![coin(a), predicate, message(b), coin(a)],
+            vec![],
+            vec![],
+        )
+        .into();
+        assert_eq!(vec![a, b], tx.required_signers());
+
+        let mint: Transaction = Transaction::mint(Default::default(), vec![]).into();
+        assert!(mint.required_signers().is_empty());
+    }
+
+    #[test]
+    fn total_input_amount_sums_inputs_of_asset() {
+        use field::Inputs;
+
+        let asset_id = AssetId::from([0xfa; 32]);
+        let other = AssetId::from([0xfb; 32]);
+
+        let coin = |amount, asset_id| {
+            Input::coin_signed(
+                Default::default(),
+                Default::default(),
+                amount,
+                asset_id,
+                Default::default(),
+                0,
+                0,
+            )
+        };
+        let message = Input::message_signed(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            1_000,
+            0,
+            0,
+            vec![],
+        );
+
+        let mut tx: Transaction = Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![
+                coin(10, asset_id),
+                coin(20, other),
+                message,
+                coin(30, asset_id),
+            ],
+            vec![],
+            vec![],
+        )
+        .into();
+        assert_eq!(Ok(40), tx.total_input_amount(&asset_id));
+        assert_eq!(Ok(20), tx.total_input_amount(&other));
+        assert_eq!(Ok(1_000), tx.total_input_amount(&AssetId::BASE));
+
+        let inputs = tx.as_script_mut().expect("script tx").inputs_mut();
+        *inputs[0].amount_mut().expect("coin input") = Word::MAX;
+        assert_eq!(
+            Err(CheckError::ArithmeticOverflow),
+            tx.total_input_amount(&asset_id)
+        );
+    }
+
+    #[test]
+    fn output_asset_ids_skip_assetless_outputs() {
+        let a = AssetId::from([0xaa; 32]);
+        let b = AssetId::from([0xbb; 32]);
+
+        let script = Transaction::script(
+            0,
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![],
+            vec![
+                Output::coin(Default::default(), 1, a),
+                Output::contract(0, Default::default(), Default::default()),
+                Output::change(Default::default(), 0, b),
+                Output::message(Default::default(), 0),
+                Output::variable(Default::default(), 0, a),
+                Output::contract_created(Default::default(), Default::default()),
+            ],
+            vec![],
+        );
+
+        assert_eq!(vec![&a, &b, &a], script.output_asset_ids().collect_vec());
+
+        let mut unique = script.output_asset_ids_unique().collect_vec();
+        unique.sort();
+        assert_eq!(vec![&a, &b], unique);
+    }
+
+    #[test]
+    fn can_decode_supported_versions_only() {
+        assert!(Transaction::SUPPORTED_VERSIONS.contains(&Transaction::VERSION));
+        assert!(Transaction::can_decode_version(Transaction::VERSION));
+        assert!(!Transaction::can_decode_version(u16::MAX));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn set_receipts_root_from_writes_receipts_root() {
+        use crate::field::ReceiptsRoot;
+
+        let receipts = vec![
+            Receipt::ret(Default::default(), 1, 2, 3),
+            Receipt::log(Default::default(), 1, 2, 3, 4, 5, 6),
+            Receipt::script_result(crate::ScriptExecutionResult::Success, 10),
+        ];
+        let mut script = Transaction::script(0, 0, 0, vec![], vec![], vec![], vec![], vec![]);
+        assert_eq!(&Bytes32::zeroed(), script.receipts_root());
+
+        script.set_receipts_root_from(&receipts);
+
+        let root = Receipt::root(&receipts);
+        assert_ne!(Bytes32::zeroed(), root);
+        assert_eq!(&root, script.receipts_root());
+
+        script.set_receipts_root_from(&receipts[..2]);
+        assert_ne!(&root, script.receipts_root());
+    }
 }
@@ -1,21 +1,118 @@
-use super::{Input, Output, Transaction, Witness};
+use super::{Input, Output, Transaction, UtxoId, Witness};
 use core::hash::Hash;
 
-use fuel_types::{AssetId, Word};
+use fuel_types::{Bytes32, ContractId, MessageId, Word};
 
 #[cfg(feature = "std")]
-use fuel_types::Bytes32;
-
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use fuel_crypto::Message;
 #[cfg(feature = "std")]
-use fuel_crypto::{Message, Signature};
+use fuel_types::Address;
 use itertools::Itertools;
 
 mod error;
 
 use crate::transaction::consensus_parameters::ConsensusParameters;
-use crate::transaction::{field, Executable};
+use crate::transaction::{field, intrinsic_gas, Executable};
 pub use error::CheckError;
 
+/// Owners of the predicates of a transaction, computed once per distinct predicate.
+#[cfg(feature = "std")]
+pub(crate) struct PredicateOwners<'a, F> {
+    owners: BTreeMap<&'a [u8], Address>,
+    compute: F,
+}
+
+#[cfg(feature = "std")]
+impl Default for PredicateOwners<'_, fn(&[u8]) -> Address> {
+    fn default() -> Self {
+        Self::new(|predicate| Input::predicate_owner(predicate))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, F> PredicateOwners<'a, F>
+where
+    F: FnMut(&[u8]) -> Address,
+{
+    pub(crate) fn new(compute: F) -> Self {
+        Self {
+            owners: BTreeMap::new(),
+            compute,
+        }
+    }
+
+    pub(crate) fn get(&mut self, predicate: &'a [u8]) -> Address {
+        let compute = &mut self.compute;
+
+        *self
+            .owners
+            .entry(predicate)
+            .or_insert_with(|| compute(predicate))
+    }
+}
+
+/// Validates the signatures of all the inputs, computing each predicate owner once.
+#[cfg(feature = "std")]
+pub(crate) fn check_inputs_signatures(
+    inputs: &[Input],
+    txhash: &Bytes32,
+    witnesses: &[Witness],
+) -> Result<(), CheckError> {
+    check_inputs_signatures_with(inputs, txhash, witnesses, PredicateOwners::default())
+}
+
+#[cfg(feature = "std")]
+fn check_inputs_signatures_with<'a, F>(
+    inputs: &'a [Input],
+    txhash: &Bytes32,
+    witnesses: &[Witness],
+    mut owners: PredicateOwners<'a, F>,
+) -> Result<(), CheckError>
+where
+    F: FnMut(&[u8]) -> Address,
+{
+    inputs.iter().enumerate().try_for_each(|(index, input)| {
+        input
+            .check_signature_with(index, txhash, witnesses, &mut owners)
+            .map(|_| ())
+    })
+}
+
+/// Reason an input passed [`Input::check_signature_detailed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignatureCheck {
+    /// The witness is a valid signature of the input owner.
+    Verified,
+    /// The owner of the predicate input is the root of its predicate.
+    PredicateOwnerValid,
+    /// The input isn't owned by an address.
+    NotApplicable,
+}
+
+/// Input shared by several inputs of the same transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Conflict {
+    /// Coin inputs spending the same UTXO.
+    UtxoId(UtxoId),
+    /// Contract inputs of the same contract.
+    ContractId(ContractId),
+    /// Message inputs of the same message.
+    MessageId(MessageId),
+}
+
+impl From<Conflict> for CheckError {
+    fn from(conflict: Conflict) -> Self {
+        match conflict {
+            Conflict::UtxoId(utxo_id) => Self::DuplicateInputUtxoId { utxo_id },
+            Conflict::ContractId(contract_id) => Self::DuplicateInputContractId { contract_id },
+            Conflict::MessageId(message_id) => Self::DuplicateMessageInputId { message_id },
+        }
+    }
+}
+
 impl Input {
     #[cfg(feature = "std")]
     pub fn check(
@@ -39,6 +136,32 @@ impl Input {
         txhash: &Bytes32,
         witnesses: &[Witness],
     ) -> Result<(), CheckError> {
+        self.check_signature_detailed(index, txhash, witnesses)
+            .map(|_| ())
+    }
+
+    /// Same as [`Self::check_signature`], but reports why the input passed.
+    #[cfg(feature = "std")]
+    pub fn check_signature_detailed(
+        &self,
+        index: usize,
+        txhash: &Bytes32,
+        witnesses: &[Witness],
+    ) -> Result<SignatureCheck, CheckError> {
+        self.check_signature_with(index, txhash, witnesses, &mut PredicateOwners::default())
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn check_signature_with<'a, F>(
+        &'a self,
+        index: usize,
+        txhash: &Bytes32,
+        witnesses: &[Witness],
+        owners: &mut PredicateOwners<'a, F>,
+    ) -> Result<SignatureCheck, CheckError>
+    where
+        F: FnMut(&[u8]) -> Address,
+    {
         match self {
             Self::CoinSigned {
                 witness_index,
@@ -50,17 +173,11 @@ impl Input {
                 recipient: owner,
                 ..
             } => {
-                let witness = witnesses
+                let signature = witnesses
                     .get(*witness_index as usize)
                     .ok_or(CheckError::InputWitnessIndexBounds { index })?
-                    .as_ref();
-
-                if witness.len() != Signature::LEN {
-                    return Err(CheckError::InputInvalidSignature { index });
-                }
-
-                // Safety: checked length
-                let signature = unsafe { Signature::as_ref_unchecked(witness) };
+                    .as_signature()
+                    .ok_or(CheckError::InputInvalidSignature { index })?;
 
                 // Safety: checked length
                 let message = unsafe { Message::as_ref_unchecked(txhash.as_ref()) };
@@ -74,7 +191,7 @@ impl Input {
                     return Err(CheckError::InputInvalidSignature { index });
                 }
 
-                Ok(())
+                Ok(SignatureCheck::Verified)
             }
 
             Self::CoinPredicate {
@@ -84,11 +201,15 @@ impl Input {
                 recipient: owner,
                 predicate,
                 ..
-            } if !Input::is_predicate_owner_valid(owner, predicate) => {
-                Err(CheckError::InputPredicateOwner { index })
+            } => {
+                if owner != &owners.get(predicate) {
+                    return Err(CheckError::InputPredicateOwner { index });
+                }
+
+                Ok(SignatureCheck::PredicateOwnerValid)
             }
 
-            _ => Ok(()),
+            Self::Contract { .. } => Ok(SignatureCheck::NotApplicable),
         }
     }
 
@@ -163,12 +284,37 @@ impl Output {
         match self {
             Self::Contract { input_index, .. } => match inputs.get(*input_index as usize) {
                 Some(Input::Contract { .. }) => Ok(()),
-                _ => Err(CheckError::OutputContractInputIndex { index }),
+                Some(_) => Err(CheckError::OutputContractInputNotContract { index }),
+                None => Err(CheckError::OutputContractInputIndex { index }),
             },
 
             _ => Ok(()),
         }
     }
+
+    /// Create a new [`Output::Contract`], ensuring `input_index` points to an
+    /// [`Input::Contract`] of `inputs`.
+    ///
+    /// Since the output is not yet part of a transaction, the reported error index is
+    /// `input_index`.
+    pub fn try_contract(
+        input_index: u8,
+        inputs: &[Input],
+        balance_root: Bytes32,
+        state_root: Bytes32,
+    ) -> Result<Self, CheckError> {
+        match inputs.get(input_index as usize) {
+            Some(Input::Contract { .. }) => {
+                Ok(Self::contract(input_index, balance_root, state_root))
+            }
+            Some(_) => Err(CheckError::OutputContractInputNotContract {
+                index: input_index as usize,
+            }),
+            None => Err(CheckError::OutputContractInputIndex {
+                index: input_index as usize,
+            }),
+        }
+    }
 }
 
 /// Means that the transaction can be validated.
@@ -244,6 +390,18 @@ where
         Err(CheckError::TransactionGasLimit)?
     }
 
+    let intrinsic = intrinsic_gas(tx.inputs(), tx.outputs(), parameters);
+    if tx.gas_limit() < &intrinsic {
+        Err(CheckError::TransactionGasLimitBelowIntrinsic {
+            intrinsic,
+            limit: *tx.gas_limit(),
+        })?
+    }
+
+    if tx.maturity() > &parameters.max_maturity {
+        Err(CheckError::TransactionMaturityTooLarge)?;
+    }
+
     if tx.maturity() > &block_height {
         Err(CheckError::TransactionMaturity)?;
     }
@@ -260,30 +418,31 @@ where
         Err(CheckError::TransactionWitnessesMax)?
     }
 
-    tx.input_asset_ids_unique().try_for_each(|input_asset_id| {
-        // check for duplicate change outputs
-        if tx
-            .outputs()
-            .iter()
-            .filter_map(|output| match output {
-                Output::Change { asset_id, .. } if input_asset_id == asset_id => Some(()),
-                Output::Change { asset_id, .. }
-                    if asset_id != &AssetId::default() && input_asset_id == asset_id =>
-                {
-                    Some(())
-                }
-                _ => None,
-            })
-            .count()
-            > 1
-        {
-            return Err(CheckError::TransactionOutputChangeAssetIdDuplicated(
-                *input_asset_id,
-            ));
-        }
+    tx.input_asset_ids_unique_with_base(&parameters.base_asset_id)
+        .try_for_each(|input_asset_id| {
+            // check for duplicate change outputs
+            if tx
+                .outputs()
+                .iter()
+                .filter_map(|output| match output {
+                    Output::Change { asset_id, .. } if input_asset_id == asset_id => Some(()),
+                    Output::Change { asset_id, .. }
+                        if asset_id != &parameters.base_asset_id && input_asset_id == asset_id =>
+                    {
+                        Some(())
+                    }
+                    _ => None,
+                })
+                .count()
+                > 1
+            {
+                return Err(CheckError::TransactionOutputChangeAssetIdDuplicated(
+                    *input_asset_id,
+                ));
+            }
 
-        Ok(())
-    })?;
+            Ok(())
+        })?;
 
     // Check for duplicated input utxo id
     let duplicated_utxo_id = tx
@@ -324,7 +483,7 @@ where
 
             if let Output::Change { asset_id, .. } = output {
                 if !tx
-                    .input_asset_ids()
+                    .input_asset_ids_with_base(&parameters.base_asset_id)
                     .any(|input_asset_id| input_asset_id == asset_id)
                 {
                     return Err(CheckError::TransactionOutputChangeAssetIdNotFound(
@@ -335,7 +494,7 @@ where
 
             if let Output::Coin { asset_id, .. } = output {
                 if !tx
-                    .input_asset_ids()
+                    .input_asset_ids_with_base(&parameters.base_asset_id)
                     .any(|input_asset_id| input_asset_id == asset_id)
                 {
                     return Err(CheckError::TransactionOutputCoinAssetIdNotFound(*asset_id));
@@ -364,3 +523,55 @@ where
     #[cfg(feature = "std")]
     return iter.duplicates().next();
 }
+
+/// Every value of `iter` that occurs more than once, listed once.
+pub(crate) fn duplicates<U>(iter: impl Iterator<Item = U>) -> Vec<U>
+where
+    U: PartialEq + Ord + Copy + Hash,
+{
+    #[cfg(not(feature = "std"))]
+    return iter
+        .sorted()
+        .as_slice()
+        .windows(2)
+        .filter_map(|u| (u[0] == u[1]).then(|| u[0]))
+        .dedup()
+        .collect();
+
+    #[cfg(feature = "std")]
+    return iter.duplicates().collect();
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicate_owner_is_computed_once_per_predicate() {
+        let predicate = vec![0xfa; 32];
+        let owner = Input::predicate_owner(&predicate);
+
+        let input = Input::coin_predicate(
+            Default::default(),
+            owner,
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            predicate.clone(),
+            vec![],
+        );
+        let inputs = [input.clone(), input.clone(), input];
+
+        let mut computed = 0;
+        let owners = PredicateOwners::new(|predicate: &[u8]| {
+            computed += 1;
+            Input::predicate_owner(predicate)
+        });
+
+        check_inputs_signatures_with(&inputs, &Bytes32::zeroed(), &[], owners)
+            .expect("Failed to validate predicate owners");
+
+        assert_eq!(1, computed);
+    }
+}
@@ -1,7 +1,7 @@
 use core::fmt;
 
 use crate::UtxoId;
-use fuel_types::{AssetId, ContractId, MessageId};
+use fuel_types::{AssetId, ContractId, MessageId, Word};
 #[cfg(feature = "std")]
 use std::{error, io};
 
@@ -33,6 +33,10 @@ pub enum CheckError {
     InputMessageDataLength {
         index: usize,
     },
+    /// The transaction has no input at `index`.
+    InputIndexBounds {
+        index: usize,
+    },
     DuplicateInputUtxoId {
         utxo_id: UtxoId,
     },
@@ -45,6 +49,10 @@ pub enum CheckError {
     OutputContractInputIndex {
         index: usize,
     },
+    /// The input referenced by an `Output::Contract` exists, but isn't an `Input::Contract`.
+    OutputContractInputNotContract {
+        index: usize,
+    },
     TransactionCreateInputContract {
         index: usize,
     },
@@ -64,17 +72,41 @@ pub enum CheckError {
     TransactionCreateBytecodeWitnessIndex,
     TransactionCreateStorageSlotMax,
     TransactionCreateStorageSlotOrder,
+    /// The `Create` transaction has a gas price, but no coin or message input to pay the
+    /// deployment fee.
+    TransactionCreateNoFeeInput,
     TransactionScriptLength,
     TransactionScriptDataLength,
     TransactionScriptOutputContractCreated {
         index: usize,
     },
+    /// The fields of an `Output::Message` are set by the VM, so they must be zeroed before the
+    /// script is executed.
+    TransactionScriptOutputMessageNotZeroed {
+        index: usize,
+    },
     TransactionMintOutputIsNotCoin,
+    /// A `Mint` coin output doesn't mint anything.
+    TransactionMintZeroCoinOutput {
+        index: usize,
+    },
     /// The block height of the checking doesn't match the transaction's block height.
     /// `Mint` transaction only exists in the scope of the block.
     TransactionMintIncorrectBlockHeight,
+    /// The total amount of `asset` minted by a `Mint` transaction exceeds the supplied cap.
+    TransactionMintExceedsCap {
+        asset: AssetId,
+    },
     TransactionGasLimit,
+    /// The gas limit can't cover the signature verification and the I/O of the transaction.
+    TransactionGasLimitBelowIntrinsic {
+        intrinsic: Word,
+        limit: Word,
+    },
     TransactionMaturity,
+    /// The maturity exceeds [`crate::ConsensusParameters::max_maturity`], so the transaction
+    /// would never become spendable.
+    TransactionMaturityTooLarge,
     TransactionInputsMax,
     TransactionOutputsMax,
     TransactionWitnessesMax,
@@ -124,6 +156,6 @@ impl error::Error for CheckError {
 #[cfg(feature = "std")]
 impl From<CheckError> for io::Error {
     fn from(v: CheckError) -> io::Error {
-        io::Error::new(io::ErrorKind::Other, v)
+        io::Error::other(v)
     }
 }
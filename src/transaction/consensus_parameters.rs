@@ -1,10 +1,12 @@
 use fuel_types::bytes::WORD_SIZE;
-use fuel_types::{AssetId, Bytes32};
+use fuel_types::{AssetId, Bytes32, Word};
+
+use alloc::vec::Vec;
+use core::fmt;
 
 /// Consensus configurable parameters used for verifying transactions
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(default))]
 pub struct ConsensusParameters {
     /// Maximum contract size, in bytes.
     pub contract_max_size: u64,
@@ -29,9 +31,27 @@ pub struct ConsensusParameters {
     /// Factor to convert between gas and transaction assets value.
     pub gas_price_factor: u64,
     /// A fixed ratio linking metered bytes to gas price
+    ///
+    /// If zero, transactions pay no fee for their size. See [`ConsensusParameters::warnings`].
     pub gas_per_byte: u64,
     /// Maximum length of message data, in bytes.
     pub max_message_data_length: u64,
+    /// Maximum maturity of a transaction, as a block height.
+    ///
+    /// Defaults to [`Word::MAX`], so no maturity is too large unless the chain sets it.
+    #[cfg_attr(feature = "serde", serde(default = "default_max_maturity"))]
+    pub max_maturity: u64,
+    /// Gas charged for the fixed processing of a transaction.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gas_costs: GasCosts,
+    /// Asset used to pay the transaction fees.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub base_asset_id: AssetId,
+}
+
+#[cfg(feature = "serde")]
+const fn default_max_maturity() -> u64 {
+    ConsensusParameters::DEFAULT.max_maturity
 }
 
 impl ConsensusParameters {
@@ -50,6 +70,9 @@ impl ConsensusParameters {
         gas_price_factor: 1_000_000_000,
         gas_per_byte: 4,
         max_message_data_length: 1024 * 1024,
+        max_maturity: Word::MAX,
+        gas_costs: GasCosts::DEFAULT,
+        base_asset_id: AssetId::BASE,
     };
 
     /// Transaction memory offset in VM runtime
@@ -60,6 +83,46 @@ impl ConsensusParameters {
             + self.max_inputs as usize * (AssetId::LEN + WORD_SIZE)
     }
 
+    /// Check that the limits are consistent with each other and with the encoding of the
+    /// transactions.
+    ///
+    /// Parameters failing this check can't be used safely: the fee calculation divides by
+    /// `gas_price_factor` and witness indexes are encoded as `u8`.
+    pub fn validate(&self) -> Result<(), ConsensusParametersError> {
+        if self.max_witnesses > u8::MAX as u64 {
+            return Err(ConsensusParametersError::MaxWitnessesOverflow {
+                max_witnesses: self.max_witnesses,
+            });
+        }
+
+        if self.gas_price_factor == 0 {
+            return Err(ConsensusParametersError::ZeroGasPriceFactor);
+        }
+
+        if self.max_predicate_length > self.max_script_length {
+            return Err(ConsensusParametersError::PredicateLongerThanScript {
+                max_predicate_length: self.max_predicate_length,
+                max_script_length: self.max_script_length,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Report the settings that are valid but likely a misconfiguration.
+    ///
+    /// Every warning is a deliberate choice for some networks, so they don't prevent the
+    /// parameters from being used.
+    pub fn warnings(&self) -> Vec<ParametersWarning> {
+        let mut warnings = Vec::new();
+
+        if self.gas_per_byte == 0 {
+            warnings.push(ParametersWarning::ZeroGasPerByte);
+        }
+
+        warnings
+    }
+
     /// Replace the max contract size with the given argument
     pub const fn with_contract_max_size(self, contract_max_size: u64) -> Self {
         let Self {
@@ -75,6 +138,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -92,6 +158,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -110,6 +179,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -127,6 +199,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -145,6 +220,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -162,6 +240,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -180,6 +261,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -197,6 +281,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -215,6 +302,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -232,6 +322,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -250,6 +343,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -267,6 +363,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -285,6 +384,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -302,6 +404,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -320,6 +425,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -337,6 +445,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -355,6 +466,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -372,6 +486,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -390,6 +507,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -407,6 +527,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -425,6 +548,9 @@ impl ConsensusParameters {
             max_predicate_data_length,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -442,6 +568,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -459,6 +588,9 @@ impl ConsensusParameters {
             max_predicate_data_length,
             gas_price_factor,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -476,6 +608,9 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
 
@@ -494,6 +629,50 @@ impl ConsensusParameters {
             max_predicate_data_length,
             gas_price_factor,
             gas_per_byte,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
+            ..
+        } = self;
+
+        Self {
+            contract_max_size,
+            max_inputs,
+            max_outputs,
+            max_witnesses,
+            max_gas_per_tx,
+            max_script_length,
+            max_script_data_length,
+            max_storage_slots,
+            max_predicate_length,
+            max_predicate_data_length,
+            gas_price_factor,
+            gas_per_byte,
+            max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
+        }
+    }
+
+    /// Replace the max maturity with the given argument
+    pub const fn with_max_maturity(self, max_maturity: u64) -> Self {
+        let Self {
+            contract_max_size,
+            max_inputs,
+            max_outputs,
+            max_witnesses,
+            max_gas_per_tx,
+            max_script_length,
+            max_script_data_length,
+            max_storage_slots,
+            max_predicate_length,
+            max_predicate_data_length,
+            gas_price_factor,
+            gas_per_byte,
+            max_message_data_length,
+            gas_costs,
+            base_asset_id,
             ..
         } = self;
 
@@ -511,8 +690,161 @@ impl ConsensusParameters {
             gas_price_factor,
             gas_per_byte,
             max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
         }
     }
+
+    /// Replace the gas costs with the given argument
+    pub const fn with_gas_costs(self, gas_costs: GasCosts) -> Self {
+        let Self {
+            contract_max_size,
+            max_inputs,
+            max_outputs,
+            max_witnesses,
+            max_gas_per_tx,
+            max_script_length,
+            max_script_data_length,
+            max_storage_slots,
+            max_predicate_length,
+            max_predicate_data_length,
+            gas_price_factor,
+            gas_per_byte,
+            max_message_data_length,
+            max_maturity,
+            base_asset_id,
+            ..
+        } = self;
+
+        Self {
+            contract_max_size,
+            max_inputs,
+            max_outputs,
+            max_witnesses,
+            max_gas_per_tx,
+            max_script_length,
+            max_script_data_length,
+            max_storage_slots,
+            max_predicate_length,
+            max_predicate_data_length,
+            gas_price_factor,
+            gas_per_byte,
+            max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
+        }
+    }
+
+    /// Replace the base asset id with the given argument
+    pub const fn with_base_asset_id(self, base_asset_id: AssetId) -> Self {
+        let Self {
+            contract_max_size,
+            max_inputs,
+            max_outputs,
+            max_witnesses,
+            max_gas_per_tx,
+            max_script_length,
+            max_script_data_length,
+            max_storage_slots,
+            max_predicate_length,
+            max_predicate_data_length,
+            gas_price_factor,
+            gas_per_byte,
+            max_message_data_length,
+            max_maturity,
+            gas_costs,
+            ..
+        } = self;
+
+        Self {
+            contract_max_size,
+            max_inputs,
+            max_outputs,
+            max_witnesses,
+            max_gas_per_tx,
+            max_script_length,
+            max_script_data_length,
+            max_storage_slots,
+            max_predicate_length,
+            max_predicate_data_length,
+            gas_price_factor,
+            gas_per_byte,
+            max_message_data_length,
+            max_maturity,
+            gas_costs,
+            base_asset_id,
+        }
+    }
+}
+
+/// Inconsistent setting of the [`ConsensusParameters`], reported by
+/// [`ConsensusParameters::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ConsensusParametersError {
+    /// `max_witnesses` exceeds the witness indexes addressable by a `u8`.
+    MaxWitnessesOverflow { max_witnesses: u64 },
+    /// `gas_price_factor` is zero, so the fee can't be computed.
+    ZeroGasPriceFactor,
+    /// `max_predicate_length` is greater than `max_script_length`.
+    PredicateLongerThanScript {
+        max_predicate_length: u64,
+        max_script_length: u64,
+    },
+}
+
+impl fmt::Display for ConsensusParametersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConsensusParametersError {}
+
+/// Suspicious setting of the [`ConsensusParameters`], reported by
+/// [`ConsensusParameters::warnings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParametersWarning {
+    /// `gas_per_byte` is zero, so the size of the transactions is free of charge.
+    ZeroGasPerByte,
+}
+
+/// Gas charged for processing a transaction, independently of its execution
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct GasCosts {
+    /// Gas to verify the signature of a signed input.
+    pub signature_verification: Word,
+    /// Gas to process an input.
+    pub input: Word,
+    /// Gas to process an output.
+    pub output: Word,
+    /// Gas to verify a byte of predicate bytecode, used to estimate the gas of the predicates.
+    pub predicate_per_byte: Word,
+}
+
+impl GasCosts {
+    /// Default gas costs
+    ///
+    /// Every cost is zero, so transactions pay no intrinsic gas unless the chain sets them.
+    pub const DEFAULT: Self = Self {
+        signature_verification: 0,
+        input: 0,
+        output: 0,
+        predicate_per_byte: 0,
+    };
+}
+
+impl Default for GasCosts {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
 }
 
 impl Default for ConsensusParameters {
@@ -521,6 +853,60 @@ impl Default for ConsensusParameters {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_inconsistent_limits() {
+        let params = ConsensusParameters::DEFAULT;
+        assert_eq!(Ok(()), params.validate());
+
+        assert_eq!(
+            Err(ConsensusParametersError::MaxWitnessesOverflow { max_witnesses: 256 }),
+            params.with_max_witnesses(256).validate()
+        );
+
+        assert_eq!(
+            Err(ConsensusParametersError::ZeroGasPriceFactor),
+            params.with_gas_price_factor(0).validate()
+        );
+
+        assert_eq!(
+            Err(ConsensusParametersError::PredicateLongerThanScript {
+                max_predicate_length: 11,
+                max_script_length: 10,
+            }),
+            params
+                .with_max_script_length(10)
+                .with_max_predicate_length(11)
+                .validate()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_defaults_only_the_newest_fields() {
+        let json = serde_json::to_value(ConsensusParameters::DEFAULT).expect("serialize params");
+
+        let mut legacy = json.clone();
+        ["max_maturity", "gas_costs", "base_asset_id"]
+            .iter()
+            .for_each(|field| {
+                legacy.as_object_mut().unwrap().remove(*field);
+            });
+
+        let params: ConsensusParameters =
+            serde_json::from_value(legacy).expect("the latest fields are optional");
+        assert_eq!(ConsensusParameters::DEFAULT, params);
+
+        let mut missing = json;
+        missing.as_object_mut().unwrap().remove("max_gas_per_tx");
+
+        assert!(serde_json::from_value::<ConsensusParameters>(missing).is_err());
+    }
+}
+
 /// Arbitrary default consensus parameters. While best-efforts are made to adjust these to
 /// reasonable settings, they may not be useful for every network instantiation.
 #[deprecated(since = "0.12.2", note = "use `ConsensusParameters` instead.")]
@@ -0,0 +1,171 @@
+use crate::checked_transaction::initial_free_balances;
+use crate::transaction::field::{Inputs, Outputs, Witnesses};
+use crate::{
+    Chargeable, CheckError, Checkable, ConsensusParameters, Transaction, TransactionFee,
+    UniqueIdentifier,
+};
+
+use fuel_types::{Address, AssetId, Word};
+
+use std::collections::BTreeMap;
+
+/// Preview of the checks and the fee of a transaction, as reported by
+/// [`Transaction::dry_run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DryRunReport {
+    /// Every distinct error found by the checks, instead of only the first one.
+    pub validation_errors: Vec<CheckError>,
+    /// Maximum fee of the transaction; `None` if it overflows.
+    pub fee: Option<TransactionFee>,
+    /// Balances left once the fee is paid; empty if the inputs can't cover the fee.
+    pub free_balances: BTreeMap<AssetId, Word>,
+    /// Addresses that must sign the transaction, as [`Transaction::required_signers`].
+    pub required_signers: Vec<Address>,
+}
+
+impl DryRunReport {
+    /// Whether the transaction passed every check.
+    pub fn is_valid(&self) -> bool {
+        self.validation_errors.is_empty()
+    }
+}
+
+impl Transaction {
+    /// Run the checks of the transaction without stopping at the first error, and compute
+    /// its fee and balances, so wallets can preview it in a single call.
+    pub fn dry_run(&self, block_height: Word, params: &ConsensusParameters) -> DryRunReport {
+        let required_signers = self.required_signers();
+
+        let (validation_errors, fee, free_balances) = match self {
+            Self::Script(script) => dry_run(script, block_height, params),
+            Self::Create(create) => dry_run(create, block_height, params),
+            Self::Mint(mint) => (
+                mint.check(block_height, params).err().into_iter().collect(),
+                Some(TransactionFee::default()),
+                BTreeMap::new(),
+            ),
+        };
+
+        DryRunReport {
+            validation_errors,
+            fee,
+            free_balances,
+            required_signers,
+        }
+    }
+}
+
+fn dry_run<T>(
+    tx: &T,
+    block_height: Word,
+    params: &ConsensusParameters,
+) -> (
+    Vec<CheckError>,
+    Option<TransactionFee>,
+    BTreeMap<AssetId, Word>,
+)
+where
+    T: Checkable + Chargeable + UniqueIdentifier + Inputs + Outputs + Witnesses,
+{
+    let id = tx.id();
+    let (inputs, outputs, witnesses) = (tx.inputs(), tx.outputs(), tx.witnesses());
+
+    let mut errors = Vec::new();
+
+    inputs.iter().enumerate().for_each(|(index, input)| {
+        errors.extend(
+            input
+                .check_without_signature(index, outputs, witnesses, params)
+                .err(),
+        );
+        errors.extend(input.check_signature(index, &id, witnesses).err());
+    });
+
+    outputs
+        .iter()
+        .enumerate()
+        .for_each(|(index, output)| errors.extend(output.check(index, inputs).err()));
+
+    errors.extend(tx.check_without_signatures(block_height, params).err());
+
+    let fee = TransactionFee::checked_from_tx(params, tx);
+    let free_balances = match initial_free_balances(tx, params) {
+        Ok(balances) => balances.initial_free_balances,
+        Err(e) => {
+            errors.push(e);
+            BTreeMap::new()
+        }
+    };
+
+    let mut validation_errors: Vec<CheckError> = Vec::with_capacity(errors.len());
+    errors.into_iter().for_each(|e| {
+        if !validation_errors.contains(&e) {
+            validation_errors.push(e);
+        }
+    });
+
+    (validation_errors, fee, free_balances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Input, Output, TransactionBuilder};
+
+    use fuel_crypto::SecretKey;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn dry_run_reports_every_error() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let params = ConsensusParameters::DEFAULT;
+        let block_height = 100;
+
+        let secret = SecretKey::random(rng);
+        let owner = Input::owner(&secret.public_key());
+        let other_owner = rng.gen();
+        let asset_id = rng.gen();
+
+        let tx: Transaction = TransactionBuilder::script(vec![], vec![])
+            .gas_limit(1_000)
+            .gas_price(1)
+            .add_unsigned_coin_input(secret, rng.gen(), 1_000, params.base_asset_id, rng.gen(), 0)
+            // Witness index out of bounds
+            .add_input(Input::coin_signed(
+                rng.gen(),
+                other_owner,
+                100,
+                asset_id,
+                rng.gen(),
+                10,
+                0,
+            ))
+            // Contract output pointing to a coin input
+            .add_output(Output::contract(0, rng.gen(), rng.gen()))
+            .finalize()
+            .into();
+
+        let report = tx.dry_run(block_height, &params);
+
+        assert!(!report.is_valid());
+        assert!(report
+            .validation_errors
+            .contains(&CheckError::InputWitnessIndexBounds { index: 1 }));
+        assert!(report
+            .validation_errors
+            .contains(&CheckError::OutputContractInputNotContract { index: 0 }));
+
+        let script = tx.as_script().expect("script tx");
+        assert_eq!(TransactionFee::checked_from_tx(&params, script), report.fee);
+        assert_eq!(Some(&100), report.free_balances.get(&asset_id));
+        assert_eq!(vec![owner, other_owner], report.required_signers);
+
+        let mint: Transaction = TransactionBuilder::mint(block_height as u32, 0)
+            .finalize()
+            .into();
+        let report = mint.dry_run(block_height, &params);
+        assert!(report.is_valid());
+        assert_eq!(Some(TransactionFee::default()), report.fee);
+    }
+}
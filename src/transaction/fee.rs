@@ -64,6 +64,9 @@ impl TransactionFee {
     /// Attempt to create a transaction fee from parameters and value arguments
     ///
     /// Will return `None` if arithmetic overflow occurs.
+    ///
+    /// A zero `gas_per_byte` makes the metered bytes free, so the fee is computed from the gas
+    /// limit alone. [`ConsensusParameters::warnings`] reports it as a likely misconfiguration.
     pub fn checked_from_values(
         params: &ConsensusParameters,
         metered_bytes: Word,
@@ -129,7 +132,7 @@ pub trait Chargeable {
 
 #[cfg(test)]
 mod tests {
-    use crate::{ConsensusParameters, TransactionFee, Word};
+    use crate::{ConsensusParameters, ParametersWarning, TransactionFee, Word};
 
     const PARAMS: ConsensusParameters = ConsensusParameters::DEFAULT
         .with_gas_per_byte(2)
@@ -191,6 +194,29 @@ mod tests {
         assert_eq!(expected, fee);
     }
 
+    #[test]
+    fn base_fee_zero_gas_per_byte() {
+        let params = PARAMS.with_gas_per_byte(0);
+        let metered_bytes = Word::MAX;
+        let gas_limit = 7;
+        let gas_price = 11;
+
+        let fee = TransactionFee::checked_from_values(&params, metered_bytes, gas_limit, gas_price)
+            .expect("failed to calculate fee");
+
+        let expected = gas_limit * gas_price;
+        let expected = expected as f64 / params.gas_price_factor as f64;
+        let expected = expected.ceil() as Word;
+
+        assert_eq!(0, TransactionFee::min(&fee));
+        assert_eq!(0, fee.min_gas());
+        assert_eq!(gas_limit, fee.max_gas());
+        assert_eq!(expected, fee.total());
+
+        assert_eq!(vec![ParametersWarning::ZeroGasPerByte], params.warnings());
+        assert!(PARAMS.warnings().is_empty());
+    }
+
     #[test]
     fn base_fee_wont_overflow_on_bytes() {
         let metered_bytes = Word::MAX;
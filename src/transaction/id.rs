@@ -1,4 +1,4 @@
-use crate::{field, Input, Transaction};
+use crate::{field, Input, Transaction, Witness};
 
 use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
 use fuel_types::Bytes32;
@@ -7,6 +7,12 @@ use fuel_types::Bytes32;
 pub trait UniqueIdentifier {
     /// The unique identifier of the transaction is based on its content.
     fn id(&self) -> Bytes32;
+
+    /// The message signed by the owners of the inputs; it wraps the [`Self::id`].
+    fn hash_to_sign(&self) -> Message {
+        // Safety: the transaction id is the output of a cryptographically secure hash
+        unsafe { Message::from_bytes_unchecked(*self.id()) }
+    }
 }
 
 impl UniqueIdentifier for Transaction {
@@ -38,12 +44,9 @@ where
 
         let pk = PublicKey::from(secret);
         let pk = Input::owner(&pk);
-        let id = self.id();
+        let message = self.hash_to_sign();
 
-        // Safety: checked length
-        let message = unsafe { Message::as_ref_unchecked(id.as_ref()) };
-
-        let signature = Signature::sign(secret, message);
+        let signature = Signature::sign(secret, &message);
 
         let inputs = self.inputs();
 
@@ -67,7 +70,7 @@ where
 
         for w in witness_indexes {
             if let Some(w) = self.witnesses_mut().get_mut(w) {
-                *w = signature.as_ref().into();
+                *w = Witness::from_signature(&signature);
             }
         }
     }
@@ -220,6 +223,8 @@ mod tests {
 
             assert_io_eq!(tx, outputs_mut, Output::Message, recipient, invert);
             assert_io_eq!(tx, outputs_mut, Output::Message, amount, not);
+            assert_io_eq!(tx, outputs_mut, Output::Message, nonce, invert);
+            assert_io_eq!(tx, outputs_mut, Output::Message, digest, invert);
 
             assert_io_ne!(tx, outputs_mut, Output::Change, to, invert);
             assert_io_eq!(tx, outputs_mut, Output::Change, amount, not);
@@ -245,11 +250,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn hash_to_sign_produces_valid_signatures() {
+        use crate::field::Witnesses;
+        use fuel_crypto::{SecretKey, Signature};
+
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let secret = SecretKey::random(rng);
+
+        let mut tx = TransactionBuilder::script(vec![], vec![])
+            .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+            .finalize_without_signature();
+
+        let message = tx.hash_to_sign();
+        assert_eq!(tx.id().as_ref(), message.as_ref());
+
+        let signature = Signature::sign(&secret, &message);
+        tx.witnesses_mut()[0] = Witness::from_signature(&signature);
+
+        tx.check_signatures()
+            .expect("signature over hash_to_sign should be valid");
+
+        let tx: Transaction = tx.into();
+        assert_eq!(message, tx.hash_to_sign());
+    }
+
+    #[test]
+    fn id_digest_without_witnesses_ignores_witnesses() {
+        use crate::field::{Maturity, ReceiptsRoot, Witnesses};
+        use fuel_crypto::SecretKey;
+
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let secret = SecretKey::random(rng);
+
+        let mut script = TransactionBuilder::script(vec![0xfa; 8], vec![])
+            .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+            .finalize_without_signature();
+        *script.receipts_root_mut() = rng.gen();
+
+        let digest = script.id_digest_without_witnesses();
+        assert_eq!(script.id(), digest);
+
+        script.witnesses_mut()[0] = generate_bytes(rng).into();
+        *script.receipts_root_mut() = rng.gen();
+        assert_eq!(digest, script.id_digest_without_witnesses());
+
+        // The cached id is ignored
+        script.precompute();
+        *script.maturity_mut() += 1;
+        assert_ne!(script.id(), script.id_digest_without_witnesses());
+
+        let mut create = TransactionBuilder::create(generate_bytes(rng).into(), rng.gen(), vec![])
+            .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+            .finalize_without_signature();
+
+        let digest = create.id_digest_without_witnesses();
+        assert_eq!(create.id(), digest);
+
+        create.witnesses_mut()[1] = generate_bytes(rng).into();
+        assert_eq!(digest, create.id_digest_without_witnesses());
+    }
+
     #[test]
     fn id() {
         let rng = &mut StdRng::seed_from_u64(8586);
 
-        let inputs = vec![
+        let inputs = [
             vec![],
             vec![
                 Input::coin_signed(
@@ -275,7 +341,7 @@ mod tests {
             ],
         ];
 
-        let outputs = vec![
+        let outputs = [
             vec![],
             vec![
                 Output::coin(rng.gen(), rng.next_u64(), rng.gen()),
@@ -287,14 +353,14 @@ mod tests {
             ],
         ];
 
-        let witnesses = vec![
+        let witnesses = [
             vec![],
             vec![generate_bytes(rng).into(), generate_bytes(rng).into()],
         ];
 
-        let scripts = vec![vec![], generate_bytes(rng), generate_bytes(rng)];
-        let script_data = vec![vec![], generate_bytes(rng), generate_bytes(rng)];
-        let storage_slots = vec![vec![], vec![rng.gen(), rng.gen()]];
+        let scripts = [vec![], generate_bytes(rng), generate_bytes(rng)];
+        let script_data = [vec![], generate_bytes(rng), generate_bytes(rng)];
+        let storage_slots = [vec![], vec![rng.gen(), rng.gen()]];
 
         for inputs in inputs.iter() {
             for outputs in outputs.iter() {
@@ -1,7 +1,10 @@
 use crate::Transaction;
 
 #[cfg(feature = "std")]
-use fuel_types::Word;
+use fuel_types::{bytes, bytes::WORD_SIZE, Word};
+
+#[cfg(feature = "std")]
+use std::io;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -21,13 +24,27 @@ impl From<&Transaction> for TransactionRepr {
     }
 }
 
+#[cfg(feature = "std")]
+impl TransactionRepr {
+    /// Read the representation from the identifier word of a serialized transaction, without
+    /// decoding its body.
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() < WORD_SIZE {
+            return Err(bytes::eof());
+        }
+
+        // Safety: buffer size is checked
+        let (identifier, _): (Word, _) = unsafe { bytes::restore_number_unchecked(bytes) };
+
+        Self::try_from(identifier)
+    }
+}
+
 #[cfg(feature = "std")]
 impl TryFrom<Word> for TransactionRepr {
-    type Error = std::io::Error;
+    type Error = io::Error;
 
     fn try_from(b: Word) -> Result<Self, Self::Error> {
-        use std::io;
-
         match b {
             0x00 => Ok(Self::Script),
             0x01 => Ok(Self::Create),
@@ -0,0 +1,106 @@
+use crate::Transaction;
+
+use core::borrow::Borrow;
+use core::ops::Deref;
+use std::sync::Arc;
+
+/// Reference counted, immutable transaction.
+///
+/// Cloning it doesn't copy the scripts, predicates and witnesses of the transaction, so it is
+/// cheap to keep many references to the same transaction, e.g. in a mempool.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SharedTransaction(Arc<Transaction>);
+
+impl SharedTransaction {
+    /// Returns the inner transaction, cloning it if other references to it are alive.
+    pub fn into_inner(self) -> Transaction {
+        Arc::try_unwrap(self.0).unwrap_or_else(|tx| tx.as_ref().clone())
+    }
+
+    /// Whether both references point to the same transaction in memory.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl Transaction {
+    /// Copy the transaction into a [`SharedTransaction`], whose clones share its buffers.
+    pub fn shared(&self) -> SharedTransaction {
+        self.clone().into()
+    }
+}
+
+impl From<Transaction> for SharedTransaction {
+    fn from(tx: Transaction) -> Self {
+        Self(Arc::new(tx))
+    }
+}
+
+impl From<SharedTransaction> for Transaction {
+    fn from(tx: SharedTransaction) -> Self {
+        tx.into_inner()
+    }
+}
+
+impl Deref for SharedTransaction {
+    type Target = Transaction;
+
+    fn deref(&self) -> &Transaction {
+        &self.0
+    }
+}
+
+impl AsRef<Transaction> for SharedTransaction {
+    fn as_ref(&self) -> &Transaction {
+        &self.0
+    }
+}
+
+impl Borrow<Transaction> for SharedTransaction {
+    fn borrow(&self) -> &Transaction {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{Script as ScriptField, Witnesses};
+
+    #[test]
+    fn shared_clones_share_buffers() {
+        let tx: Transaction = Transaction::script(
+            0,
+            0,
+            0,
+            vec![0xfa; 64],
+            vec![],
+            vec![],
+            vec![],
+            vec![vec![0xfb; 64].into()],
+        )
+        .into();
+
+        let shared = tx.shared();
+        let clone = shared.clone();
+        assert!(SharedTransaction::ptr_eq(&shared, &clone));
+        assert_eq!(tx, *clone);
+
+        let (script, script_p) = (
+            shared.as_script().expect("script tx"),
+            clone.as_script().expect("script tx"),
+        );
+        assert_eq!(script.script().as_ptr(), script_p.script().as_ptr());
+        assert_eq!(
+            script.witnesses()[0].as_ref().as_ptr(),
+            script_p.witnesses()[0].as_ref().as_ptr()
+        );
+
+        // The original transaction isn't shared
+        let original = tx.as_script().expect("script tx");
+        assert_ne!(original.script().as_ptr(), script.script().as_ptr());
+
+        drop(shared);
+        assert_eq!(tx, clone.into_inner());
+    }
+}
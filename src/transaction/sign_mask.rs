@@ -0,0 +1,155 @@
+#![allow(non_upper_case_globals)]
+
+use crate::transaction::field::{Inputs, Outputs, ReceiptsRoot, Witnesses};
+use crate::{Input, Output, Script, Transaction};
+
+bitflags::bitflags! {
+    /// Fields of a transaction emptied to produce its signing pre-image.
+    pub struct SignMask: u32 {
+        /// Clear the witnesses.
+        const Witnesses     = 0b00000001;
+        /// Zero the receipts root of scripts.
+        const ReceiptsRoot  = 0b00000010;
+        /// Zero the fields of the inputs that are set by the block producer, such as the
+        /// transaction pointers and the contract roots.
+        const InputPointers = 0b00000100;
+        /// Zero the fields of the outputs that are set by the VM.
+        const Outputs       = 0b00001000;
+        /// The fields emptied to compute the transaction id.
+        const All           = Self::Witnesses.bits
+                            | Self::ReceiptsRoot.bits
+                            | Self::InputPointers.bits
+                            | Self::Outputs.bits;
+    }
+}
+
+impl Default for SignMask {
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+/// Empties the inputs, outputs and witnesses of `tx` selected by `mask`.
+pub(crate) fn prepare_sign_io<T>(tx: &mut T, mask: SignMask)
+where
+    T: Inputs + Outputs + Witnesses,
+{
+    if mask.contains(SignMask::InputPointers) {
+        tx.inputs_mut().iter_mut().for_each(Input::prepare_sign);
+    }
+
+    if mask.contains(SignMask::Outputs) {
+        tx.outputs_mut().iter_mut().for_each(Output::prepare_sign);
+    }
+
+    if mask.contains(SignMask::Witnesses) {
+        tx.witnesses_mut().clear();
+    }
+}
+
+/// Empties the fields of `script` selected by `mask`, including its receipts root.
+pub(crate) fn prepare_sign_script(script: &mut Script, mask: SignMask) {
+    if mask.contains(SignMask::ReceiptsRoot) {
+        *script.receipts_root_mut() = Default::default();
+    }
+
+    prepare_sign_io(script, mask);
+}
+
+impl Transaction {
+    /// Empties the fields selected by `mask`, producing a custom signing pre-image.
+    ///
+    /// [`SignMask::All`] empties the same fields as the computation of the transaction id.
+    pub fn prepare_sign_with(&mut self, mask: SignMask) {
+        match self {
+            Self::Script(script) => prepare_sign_script(script, mask),
+
+            Self::Create(create) => prepare_sign_io(create, mask),
+
+            Self::Mint(mint) => {
+                if mask.contains(SignMask::Outputs) {
+                    mint.outputs_mut().iter_mut().for_each(Output::prepare_sign);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{TransactionBuilder, TxPointer, UniqueIdentifier};
+
+    use fuel_crypto::SecretKey;
+    use fuel_types::bytes::SerializableVec;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn script(rng: &mut StdRng) -> Transaction {
+        let secret = SecretKey::random(rng);
+
+        let mut script = TransactionBuilder::script(vec![0xfa; 8], vec![])
+            .add_unsigned_coin_input(
+                secret,
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                TxPointer::new(1, 2),
+                0,
+            )
+            .add_output(Output::change(rng.gen(), 10, rng.gen()))
+            .finalize();
+
+        *script.receipts_root_mut() = rng.gen();
+
+        script.into()
+    }
+
+    #[test]
+    fn prepare_sign_with_all_matches_id() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let tx = script(rng);
+
+        let mut preimage = tx.clone();
+        preimage.prepare_sign_with(SignMask::All);
+
+        assert_eq!(
+            tx.id(),
+            fuel_crypto::Hasher::hash(preimage.to_bytes().as_slice())
+        );
+    }
+
+    #[test]
+    fn prepare_sign_with_masks() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let tx = script(rng);
+
+        let mut witnesses_only = tx.clone();
+        witnesses_only.prepare_sign_with(SignMask::Witnesses);
+
+        let script = witnesses_only.as_script().expect("script tx");
+        assert!(script.witnesses().is_empty());
+        assert_eq!(tx.as_script().unwrap().inputs(), script.inputs());
+        assert_eq!(tx.as_script().unwrap().outputs(), script.outputs());
+        assert_eq!(
+            tx.as_script().unwrap().receipts_root(),
+            script.receipts_root()
+        );
+
+        let mut io = tx.clone();
+        io.prepare_sign_with(SignMask::InputPointers | SignMask::Outputs);
+
+        let script = io.as_script().expect("script tx");
+        assert_eq!(Some(&TxPointer::default()), script.inputs()[0].tx_pointer());
+        assert_eq!(Some(0), script.outputs()[0].amount());
+        assert_eq!(tx.as_script().unwrap().witnesses(), script.witnesses());
+        assert_eq!(
+            tx.as_script().unwrap().receipts_root(),
+            script.receipts_root()
+        );
+
+        let mut none = tx.clone();
+        none.prepare_sign_with(SignMask::empty());
+        assert_eq!(tx, none);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{field, Input, Output, Transaction, UniqueIdentifier};
+
+use fuel_types::{Address, AssetId, Bytes32, Word};
+
+use core::fmt;
+
+/// Value moved by an input or an output of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SigningSummaryEntry {
+    /// Owner of a spent input, or recipient of an output.
+    pub account: Address,
+    /// Amount moved.
+    pub amount: Word,
+    /// Asset moved; `None` for messages, which always carry the base asset.
+    pub asset_id: Option<AssetId>,
+}
+
+impl fmt::Display for SigningSummaryEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.asset_id {
+            Some(asset_id) => write!(f, "{} of asset {:x}", self.amount, asset_id),
+            None => write!(f, "{} of the base asset", self.amount),
+        }
+    }
+}
+
+/// What the owners of the inputs authorize by signing a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SigningSummary {
+    /// Id of the transaction, wrapped by [`UniqueIdentifier::hash_to_sign`].
+    pub id: Bytes32,
+    /// Coins and messages spent by the transaction.
+    pub inputs: Vec<SigningSummaryEntry>,
+    /// Coin, message and change outputs of the transaction.
+    ///
+    /// Variable outputs are omitted since they are set during the execution.
+    pub outputs: Vec<SigningSummaryEntry>,
+}
+
+impl fmt::Display for SigningSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Transaction {:x}", self.id)?;
+
+        self.inputs
+            .iter()
+            .try_for_each(|input| writeln!(f, "Spend {} owned by {:x}", input, input.account))?;
+
+        self.outputs
+            .iter()
+            .try_for_each(|output| writeln!(f, "Send {} to {:x}", output, output.account))
+    }
+}
+
+impl Transaction {
+    /// Summary of the inputs and outputs authorized by signing the transaction, for signers
+    /// that display what they sign.
+    pub fn signing_summary(&self) -> SigningSummary {
+        use field::{Inputs, Outputs};
+
+        let (inputs, outputs): (&[Input], &[Output]) = match self {
+            Self::Script(script) => (script.inputs(), script.outputs()),
+            Self::Create(create) => (create.inputs(), create.outputs()),
+            Self::Mint(mint) => (&[], mint.outputs()),
+        };
+
+        let inputs = inputs
+            .iter()
+            .filter_map(|input| match input {
+                Input::CoinSigned {
+                    owner,
+                    amount,
+                    asset_id,
+                    ..
+                }
+                | Input::CoinPredicate {
+                    owner,
+                    amount,
+                    asset_id,
+                    ..
+                } => Some(SigningSummaryEntry {
+                    account: *owner,
+                    amount: *amount,
+                    asset_id: Some(*asset_id),
+                }),
+
+                // The recipient of a message is the owner authorizing its spending
+                Input::MessageSigned {
+                    recipient, amount, ..
+                }
+                | Input::MessagePredicate {
+                    recipient, amount, ..
+                } => Some(SigningSummaryEntry {
+                    account: *recipient,
+                    amount: *amount,
+                    asset_id: None,
+                }),
+
+                _ => None,
+            })
+            .collect();
+
+        let outputs = outputs
+            .iter()
+            .filter_map(|output| match output {
+                Output::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                }
+                | Output::Change {
+                    to,
+                    amount,
+                    asset_id,
+                } => Some(SigningSummaryEntry {
+                    account: *to,
+                    amount: *amount,
+                    asset_id: Some(*asset_id),
+                }),
+
+                Output::Message {
+                    recipient, amount, ..
+                } => Some(SigningSummaryEntry {
+                    account: *recipient,
+                    amount: *amount,
+                    asset_id: None,
+                }),
+
+                _ => None,
+            })
+            .collect();
+
+        SigningSummary {
+            id: self.id(),
+            inputs,
+            outputs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransactionBuilder;
+
+    use fuel_crypto::SecretKey;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn signing_summary_lists_coin_outputs() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let secret = SecretKey::random(rng);
+
+        let coins: Vec<(Address, Word, AssetId)> =
+            (0..3).map(|_| (rng.gen(), rng.gen(), rng.gen())).collect();
+
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.add_unsigned_coin_input(secret, rng.gen(), 100, rng.gen(), rng.gen(), 0);
+        coins.iter().for_each(|(to, amount, asset_id)| {
+            builder.add_output(Output::coin(*to, *amount, *asset_id));
+        });
+        builder.add_output(Output::variable(rng.gen(), 0, rng.gen()));
+
+        let tx: Transaction = builder.finalize().into();
+        let summary = tx.signing_summary();
+
+        assert_eq!(tx.id(), summary.id);
+        assert_eq!(1, summary.inputs.len());
+        assert_eq!(Some(100), summary.inputs.first().map(|input| input.amount));
+
+        let outputs = summary
+            .outputs
+            .iter()
+            .map(|output| (output.account, output.amount, output.asset_id))
+            .collect::<Vec<_>>();
+        let expected = coins
+            .iter()
+            .map(|(to, amount, asset_id)| (*to, *amount, Some(*asset_id)))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, outputs);
+
+        let display = summary.to_string();
+        coins.iter().for_each(|(to, amount, _)| {
+            assert!(display.contains(&format!("Send {} of asset", amount)));
+            assert!(display.contains(&format!("to {:x}", to)));
+        });
+    }
+}
@@ -1,12 +1,96 @@
 use super::TransactionRepr;
-use crate::{Create, Mint, Script, Transaction};
+use crate::{Cacheable, Create, Mint, Script, Transaction, Witness};
 
-use fuel_types::bytes::{self, SizedBytes, WORD_SIZE};
+use fuel_types::bytes::{self, Deserializable, SizedBytes, WORD_SIZE};
 use fuel_types::Word;
 
-use std::io::{self, Write};
+use alloc::vec::Vec;
+use std::io::{self, Read, Write};
+
+/// Fixed-size header of a serialized transaction.
+///
+/// `Mint` transactions have neither gas, maturity, inputs nor witnesses, so these are zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransactionHeader {
+    pub kind: TransactionRepr,
+    pub gas_price: Word,
+    pub gas_limit: Word,
+    pub maturity: Word,
+    pub inputs_len: usize,
+    pub outputs_len: usize,
+    pub witnesses_len: usize,
+}
 
 impl Transaction {
+    /// Decode a serialized transaction and precompute its metadata, so its id and offsets are
+    /// readily available.
+    pub fn decode_and_precompute(bytes: &[u8]) -> io::Result<Self> {
+        let mut tx = Self::from_bytes(bytes)?;
+        tx.precompute();
+
+        Ok(tx)
+    }
+
+    /// Decode the header of a serialized transaction without decoding its variable-length
+    /// body.
+    pub fn decode_header(bytes: &[u8]) -> io::Result<TransactionHeader> {
+        let kind = TransactionRepr::from_bytes(bytes)?;
+        let buf = &bytes[WORD_SIZE..];
+
+        let fixed_size = match kind {
+            TransactionRepr::Script => crate::consts::TRANSACTION_SCRIPT_FIXED_SIZE,
+            TransactionRepr::Create => crate::consts::TRANSACTION_CREATE_FIXED_SIZE,
+            TransactionRepr::Mint => crate::consts::TRANSACTION_MINT_FIXED_SIZE,
+        };
+
+        if bytes.len() < fixed_size {
+            return Err(bytes::eof());
+        }
+
+        if kind == TransactionRepr::Mint {
+            // Skip the tx pointer
+            let buf = &buf[2 * WORD_SIZE..];
+            let (outputs_len, _) = unsafe { bytes::restore_usize_unchecked(buf) };
+
+            return Ok(TransactionHeader {
+                kind,
+                gas_price: 0,
+                gas_limit: 0,
+                maturity: 0,
+                inputs_len: 0,
+                outputs_len,
+                witnesses_len: 0,
+            });
+        }
+
+        // Safety: buffer size is checked
+        let (gas_price, buf) = unsafe { bytes::restore_number_unchecked(buf) };
+        let (gas_limit, buf) = unsafe { bytes::restore_number_unchecked(buf) };
+        let (maturity, buf) = unsafe { bytes::restore_number_unchecked(buf) };
+
+        // Skip the script and script data lengths, or the bytecode length, bytecode witness
+        // index and storage slots count
+        let skipped = match kind {
+            TransactionRepr::Script => 2,
+            _ => 3,
+        };
+        let buf = &buf[skipped * WORD_SIZE..];
+
+        let (inputs_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
+        let (outputs_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
+        let (witnesses_len, _) = unsafe { bytes::restore_usize_unchecked(buf) };
+
+        Ok(TransactionHeader {
+            kind,
+            gas_price,
+            gas_limit,
+            maturity,
+            inputs_len,
+            outputs_len,
+            witnesses_len,
+        })
+    }
+
     pub fn try_from_bytes(bytes: &[u8]) -> io::Result<(usize, Self)> {
         let mut tx = Self::default();
 
@@ -14,6 +98,157 @@ impl Transaction {
 
         Ok((n, tx))
     }
+
+    /// Decode a serialized transaction that must span the whole of `bytes`.
+    ///
+    /// With `decode_lenient`, the bytes trailing the known fields are skipped instead of
+    /// failing with [`io::ErrorKind::InvalidData`], so transactions extended by newer formats
+    /// can still be partially read.
+    pub fn decode(bytes: &[u8], decode_lenient: bool) -> io::Result<Self> {
+        let (n, tx) = Self::try_from_bytes(bytes)?;
+
+        if !decode_lenient && n != bytes.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The transaction is followed by unknown trailing bytes",
+            ));
+        }
+
+        Ok(tx)
+    }
+
+    /// Stream the serialized transaction into the provided writer.
+    ///
+    /// The output is byte-equivalent to [`bytes::SerializableVec::to_bytes`], but the full
+    /// transaction buffer is never allocated; only the fixed-size header and one input/output at
+    /// a time are buffered. Returns the number of bytes written.
+    pub fn encode_to_writer<W>(&self, w: &mut W) -> io::Result<usize>
+    where
+        W: Write,
+    {
+        match self {
+            Self::Script(script) => {
+                let Script {
+                    gas_price,
+                    gas_limit,
+                    maturity,
+                    receipts_root,
+                    script,
+                    script_data,
+                    inputs,
+                    outputs,
+                    witnesses,
+                    ..
+                } = script;
+
+                encode_word(w, TransactionRepr::Script as Word)?;
+                encode_word(w, *gas_price)?;
+                encode_word(w, *gas_limit)?;
+                encode_word(w, *maturity)?;
+                encode_word(w, script.len() as Word)?;
+                encode_word(w, script_data.len() as Word)?;
+                encode_word(w, inputs.len() as Word)?;
+                encode_word(w, outputs.len() as Word)?;
+                encode_word(w, witnesses.len() as Word)?;
+                w.write_all(receipts_root.as_ref())?;
+
+                encode_raw_bytes(w, script)?;
+                encode_raw_bytes(w, script_data)?;
+
+                encode_items(w, inputs)?;
+                encode_items(w, outputs)?;
+                encode_witnesses(w, witnesses)?;
+            }
+
+            Self::Create(create) => {
+                let Create {
+                    gas_price,
+                    gas_limit,
+                    maturity,
+                    bytecode_length,
+                    bytecode_witness_index,
+                    storage_slots,
+                    inputs,
+                    outputs,
+                    witnesses,
+                    salt,
+                    ..
+                } = create;
+
+                encode_word(w, TransactionRepr::Create as Word)?;
+                encode_word(w, *gas_price)?;
+                encode_word(w, *gas_limit)?;
+                encode_word(w, *maturity)?;
+                encode_word(w, *bytecode_length)?;
+                encode_word(w, *bytecode_witness_index as Word)?;
+                encode_word(w, storage_slots.len() as Word)?;
+                encode_word(w, inputs.len() as Word)?;
+                encode_word(w, outputs.len() as Word)?;
+                encode_word(w, witnesses.len() as Word)?;
+                w.write_all(salt.as_ref())?;
+
+                for slot in storage_slots {
+                    w.write_all(slot.key().as_ref())?;
+                    w.write_all(slot.value().as_ref())?;
+                }
+
+                encode_items(w, inputs)?;
+                encode_items(w, outputs)?;
+                encode_witnesses(w, witnesses)?;
+            }
+
+            Self::Mint(mint) => {
+                let Mint {
+                    tx_pointer,
+                    outputs,
+                    ..
+                } = mint;
+
+                encode_word(w, TransactionRepr::Mint as Word)?;
+                encode_word(w, tx_pointer.block_height() as Word)?;
+                encode_word(w, tx_pointer.tx_index() as Word)?;
+                encode_word(w, outputs.len() as Word)?;
+
+                encode_items(w, outputs)?;
+            }
+        }
+
+        Ok(self.serialized_size())
+    }
+}
+
+fn encode_word<W: Write>(w: &mut W, word: Word) -> io::Result<()> {
+    w.write_all(&word.to_be_bytes())
+}
+
+fn encode_raw_bytes<W: Write>(w: &mut W, data: &[u8]) -> io::Result<()> {
+    const PADDING: [u8; WORD_SIZE] = [0u8; WORD_SIZE];
+
+    w.write_all(data)?;
+    w.write_all(&PADDING[..bytes::padded_len(data) - data.len()])
+}
+
+fn encode_witnesses<W: Write>(w: &mut W, witnesses: &[Witness]) -> io::Result<()> {
+    witnesses.iter().try_for_each(|witness| {
+        encode_word(w, witness.as_ref().len() as Word)?;
+        encode_raw_bytes(w, witness.as_ref())
+    })
+}
+
+fn encode_items<W, T>(w: &mut W, items: &[T]) -> io::Result<()>
+where
+    W: Write,
+    T: Read + SizedBytes + Clone,
+{
+    let mut buf = Vec::new();
+
+    items.iter().try_for_each(|item| {
+        buf.clear();
+        buf.resize(item.serialized_size(), 0);
+
+        let n = item.clone().read(buf.as_mut_slice())?;
+        w.write_all(&buf[..n])
+    })
 }
 
 impl io::Read for Transaction {
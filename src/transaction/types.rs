@@ -7,15 +7,45 @@ mod storage;
 mod utxo_id;
 mod witness;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub use create::checked::CheckedMetadata as CreateCheckedMetadata;
 pub use create::Create;
-pub use input::{Input, InputRepr};
+pub use input::{
+    Input, InputColumns, InputRepr, PredicateContext, INPUT_COIN_FIXED_SIZE, INPUT_CONTRACT_SIZE,
+    INPUT_MESSAGE_FIXED_SIZE,
+};
 pub use mint::Mint;
-pub use output::{Output, OutputRepr};
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
+pub use output::TaggedOutput;
+pub use output::{
+    Output, OutputColumns, OutputRepr, OUTPUT_CCV_SIZE, OUTPUT_CONTRACT_CREATED_SIZE,
+    OUTPUT_CONTRACT_SIZE, OUTPUT_MESSAGE_SIZE,
+};
+#[cfg(feature = "alloc")]
 pub use script::checked::CheckedMetadata as ScriptCheckedMetadata;
 pub use script::Script;
 pub use storage::StorageSlot;
 pub use utxo_id::UtxoId;
 pub use witness::Witness;
+
+/// Reject element counts that can't fit in the remaining `buf` of a decoded transaction,
+/// before allocating them.
+///
+/// Every storage slot, input, output and witness is serialized in at least a word, so larger
+/// counts come from a truncated or corrupted buffer.
+#[cfg(feature = "std")]
+pub(crate) fn check_elements_len(buf: &[u8], counts: &[usize]) -> std::io::Result<()> {
+    use fuel_types::bytes::{self, WORD_SIZE};
+
+    let capacity = buf.len() / WORD_SIZE;
+    let fits = counts
+        .iter()
+        .try_fold(0usize, |total, count| total.checked_add(*count))
+        .is_some_and(|total| total <= capacity);
+
+    if fits {
+        Ok(())
+    } else {
+        Err(bytes::eof())
+    }
+}
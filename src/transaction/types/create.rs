@@ -11,7 +11,7 @@ use crate::{
 };
 use derivative::Derivative;
 use fuel_types::bytes::{SizedBytes, WORD_SIZE};
-use fuel_types::{bytes, AssetId, Salt, Word};
+use fuel_types::{bytes, Salt, Word};
 
 #[cfg(feature = "std")]
 use std::io;
@@ -41,6 +41,15 @@ pub struct Create {
     pub(crate) metadata: Option<CommonMetadata>,
 }
 
+impl Create {
+    /// Returns the contract bytecode stored in the witness at `bytecode_witness_index`, if any.
+    pub fn bytecode(&self) -> Option<&[u8]> {
+        self.witnesses
+            .get(self.bytecode_witness_index as usize)
+            .map(|witness| witness.as_ref())
+    }
+}
+
 #[cfg(feature = "std")]
 impl crate::UniqueIdentifier for Create {
     fn id(&self) -> fuel_types::Bytes32 {
@@ -48,15 +57,25 @@ impl crate::UniqueIdentifier for Create {
             return id;
         }
 
+        self.id_digest_without_witnesses()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Create {
+    /// Hash of the transaction with the witnesses and the other fields set during the execution
+    /// emptied.
+    ///
+    /// It is the digest of [`crate::UniqueIdentifier::id`], always computed from the current
+    /// fields instead of the cached id, so transactions differing only by their witnesses share
+    /// it.
+    pub fn id_digest_without_witnesses(&self) -> fuel_types::Bytes32 {
         let mut clone = self.clone();
 
         // Empties fields that should be zero during the signing.
-        clone.inputs_mut().iter_mut().for_each(Input::prepare_sign);
-        clone
-            .outputs_mut()
-            .iter_mut()
-            .for_each(Output::prepare_sign);
-        clone.witnesses_mut().clear();
+        use crate::transaction::sign_mask::{prepare_sign_io, SignMask};
+
+        prepare_sign_io(&mut clone, SignMask::All);
 
         fuel_crypto::Hasher::hash(clone.to_bytes().as_slice())
     }
@@ -83,16 +102,12 @@ impl Chargeable for Create {
 impl Checkable for Create {
     #[cfg(feature = "std")]
     fn check_signatures(&self) -> Result<(), CheckError> {
+        use crate::transaction::checkable::check_inputs_signatures;
         use crate::UniqueIdentifier;
 
         let id = self.id();
 
-        self.inputs()
-            .iter()
-            .enumerate()
-            .try_for_each(|(index, input)| input.check_signature(index, &id, &self.witnesses))?;
-
-        Ok(())
+        check_inputs_signatures(self.inputs(), &id, &self.witnesses)
     }
 
     fn check_without_signatures(
@@ -156,7 +171,7 @@ impl Checkable for Create {
                     Err(CheckError::TransactionCreateOutputVariable { index })
                 }
 
-                Output::Change { asset_id, .. } if asset_id != &AssetId::BASE => {
+                Output::Change { asset_id, .. } if asset_id != &parameters.base_asset_id => {
                     Err(CheckError::TransactionCreateOutputChangeNotBaseAsset { index })
                 }
 
@@ -202,15 +217,16 @@ impl SizedBytes for Create {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub mod checked {
     use crate::checked_transaction::{initial_free_balances, AvailableBalances};
+    #[cfg(feature = "std")]
+    use crate::Cacheable;
     use crate::{
-        Cacheable, CheckError, Checkable, Checked, ConsensusParameters, Create, IntoChecked,
-        TransactionFee,
+        CheckError, Checkable, Checked, ConsensusParameters, Create, IntoChecked, TransactionFee,
     };
+    use alloc::collections::BTreeMap;
     use fuel_types::{AssetId, Word};
-    use std::collections::BTreeMap;
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash)]
     pub struct CheckedMetadata {
@@ -225,14 +241,28 @@ pub mod checked {
     impl IntoChecked for Create {
         type Metadata = CheckedMetadata;
 
+        #[cfg_attr(not(feature = "std"), allow(unused_mut))]
         fn into_checked_basic(
             mut self,
             block_height: Word,
             params: &ConsensusParameters,
         ) -> Result<Checked<Self>, CheckError> {
+            #[cfg(feature = "std")]
             self.precompute();
+
             self.check_without_signatures(block_height, params)?;
 
+            // Free transactions don't need to spend anything
+            let owes_fee =
+                TransactionFee::checked_from_tx(params, &self).is_some_and(|fee| fee.total() > 0);
+            let spends = self
+                .inputs
+                .iter()
+                .any(|input| input.is_coin() || input.is_message());
+            if owes_fee && !spends {
+                return Err(CheckError::TransactionCreateNoFeeInput);
+            }
+
             // validate fees and compute free balances
             let AvailableBalances {
                 initial_free_balances,
@@ -641,6 +671,17 @@ impl io::Write for Create {
         let (inputs_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
         let (outputs_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
         let (witnesses_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
+
+        super::check_elements_len(
+            buf,
+            &[
+                storage_slots_len as usize,
+                inputs_len,
+                outputs_len,
+                witnesses_len,
+            ],
+        )?;
+
         let (salt, mut buf) = unsafe { bytes::restore_array_unchecked(buf) };
 
         let salt = salt.into();
@@ -722,3 +763,42 @@ impl TryFrom<&Create> for Contract {
             .ok_or(CheckError::TransactionCreateBytecodeWitnessIndex)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Transaction;
+
+    #[test]
+    fn bytecode_returns_witness_at_bytecode_witness_index() {
+        let bytecode = vec![0xfa; 64];
+        let witnesses = vec![vec![0xaa; 32].into(), bytecode.clone().into()];
+
+        let create = Transaction::create(
+            0,
+            0,
+            0,
+            1,
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            witnesses.clone(),
+        );
+
+        assert_eq!(Some(bytecode.as_slice()), create.bytecode());
+
+        let create = Transaction::create(
+            0,
+            0,
+            0,
+            2,
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+            witnesses,
+        );
+
+        assert_eq!(None, create.bytecode());
+    }
+}
@@ -1,9 +1,10 @@
-use crate::{TxPointer, UtxoId};
+use crate::{ConsensusParameters, TxPointer, UtxoId};
 
 use fuel_crypto::{Hasher, PublicKey};
 use fuel_types::bytes;
 use fuel_types::{Address, AssetId, Bytes32, ContractId, MessageId, Word};
 
+use core::cmp::Ordering;
 use core::mem;
 
 #[cfg(feature = "std")]
@@ -14,15 +15,19 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::io;
 
+mod columns;
 mod consts;
+mod predicate;
 mod repr;
 
-use consts::*;
-
+pub use columns::InputColumns;
+pub use consts::{INPUT_COIN_FIXED_SIZE, INPUT_CONTRACT_SIZE, INPUT_MESSAGE_FIXED_SIZE};
+pub use predicate::PredicateContext;
 pub use repr::InputRepr;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Input {
     CoinSigned {
         utxo_id: UtxoId,
@@ -87,6 +92,19 @@ impl Default for Input {
     }
 }
 
+impl PartialOrd for Input {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Input {
+    /// See [`Input::canonical_cmp`].
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical_cmp(other)
+    }
+}
+
 impl bytes::SizedBytes for Input {
     fn serialized_size(&self) -> usize {
         match self {
@@ -156,6 +174,30 @@ impl Input {
         }
     }
 
+    /// Create a [`Input::CoinPredicate`] owned by the root of `predicate`.
+    pub fn coin_predicate_derive_owner(
+        utxo_id: UtxoId,
+        amount: Word,
+        asset_id: AssetId,
+        tx_pointer: TxPointer,
+        maturity: Word,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+    ) -> Self {
+        let owner = Self::predicate_owner(&predicate);
+
+        Self::coin_predicate(
+            utxo_id,
+            owner,
+            amount,
+            asset_id,
+            tx_pointer,
+            maturity,
+            predicate,
+            predicate_data,
+        )
+    }
+
     pub const fn coin_signed(
         utxo_id: UtxoId,
         owner: Address,
@@ -254,11 +296,19 @@ impl Input {
     }
 
     pub const fn asset_id(&self) -> Option<&AssetId> {
+        self.asset_id_with_base(&AssetId::BASE)
+    }
+
+    /// Asset spent by the input; messages always spend `base_asset_id`.
+    pub const fn asset_id_with_base<'a>(
+        &'a self,
+        base_asset_id: &'a AssetId,
+    ) -> Option<&'a AssetId> {
         match self {
             Input::CoinSigned { asset_id, .. } | Input::CoinPredicate { asset_id, .. } => {
                 Some(asset_id)
             }
-            Input::MessageSigned { .. } | Input::MessagePredicate { .. } => Some(&AssetId::BASE),
+            Input::MessageSigned { .. } | Input::MessagePredicate { .. } => Some(base_asset_id),
             Input::Contract { .. } => None,
         }
     }
@@ -280,6 +330,253 @@ impl Input {
         }
     }
 
+    /// Mutable reference to the amount of coin and message inputs, keeping the variant and the
+    /// remaining fields untouched.
+    pub fn amount_mut(&mut self) -> Option<&mut Word> {
+        match self {
+            Input::CoinSigned { amount, .. }
+            | Input::CoinPredicate { amount, .. }
+            | Input::MessageSigned { amount, .. }
+            | Input::MessagePredicate { amount, .. } => Some(amount),
+            Input::Contract { .. } => None,
+        }
+    }
+
+    /// Deterministic order of the inputs, independent of their insertion order.
+    ///
+    /// Inputs are ordered by [`InputRepr`], then contracts by contract id, coins by utxo id and
+    /// messages by message id, then by owner, the recipient of messages. Inputs equal on all
+    /// of these are ordered by their remaining fields, so the order is consistent with `Eq`.
+    pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+        let owner = |input: &Self| input.input_owner().or_else(|| input.recipient()).copied();
+
+        (self.repr() as u8)
+            .cmp(&(other.repr() as u8))
+            .then_with(|| self.contract_id().cmp(&other.contract_id()))
+            .then_with(|| self.utxo_id().cmp(&other.utxo_id()))
+            .then_with(|| self.message_id().cmp(&other.message_id()))
+            .then_with(|| owner(self).cmp(&owner(other)))
+            .then_with(|| self.cmp_remaining_fields(other))
+    }
+
+    /// Compare all the fields of the inputs, signed inputs first.
+    fn cmp_remaining_fields(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (
+                Self::CoinSigned {
+                    utxo_id,
+                    owner,
+                    amount,
+                    asset_id,
+                    tx_pointer,
+                    witness_index,
+                    maturity,
+                },
+                Self::CoinSigned {
+                    utxo_id: utxo_id_p,
+                    owner: owner_p,
+                    amount: amount_p,
+                    asset_id: asset_id_p,
+                    tx_pointer: tx_pointer_p,
+                    witness_index: witness_index_p,
+                    maturity: maturity_p,
+                },
+            ) => (
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer,
+                witness_index,
+                maturity,
+            )
+                .cmp(&(
+                    utxo_id_p,
+                    owner_p,
+                    amount_p,
+                    asset_id_p,
+                    tx_pointer_p,
+                    witness_index_p,
+                    maturity_p,
+                )),
+
+            (
+                Self::CoinPredicate {
+                    utxo_id,
+                    owner,
+                    amount,
+                    asset_id,
+                    tx_pointer,
+                    maturity,
+                    predicate,
+                    predicate_data,
+                },
+                Self::CoinPredicate {
+                    utxo_id: utxo_id_p,
+                    owner: owner_p,
+                    amount: amount_p,
+                    asset_id: asset_id_p,
+                    tx_pointer: tx_pointer_p,
+                    maturity: maturity_p,
+                    predicate: predicate_p,
+                    predicate_data: predicate_data_p,
+                },
+            ) => (
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer,
+                maturity,
+                predicate,
+                predicate_data,
+            )
+                .cmp(&(
+                    utxo_id_p,
+                    owner_p,
+                    amount_p,
+                    asset_id_p,
+                    tx_pointer_p,
+                    maturity_p,
+                    predicate_p,
+                    predicate_data_p,
+                )),
+
+            (
+                Self::Contract {
+                    utxo_id,
+                    balance_root,
+                    state_root,
+                    tx_pointer,
+                    contract_id,
+                },
+                Self::Contract {
+                    utxo_id: utxo_id_p,
+                    balance_root: balance_root_p,
+                    state_root: state_root_p,
+                    tx_pointer: tx_pointer_p,
+                    contract_id: contract_id_p,
+                },
+            ) => (contract_id, utxo_id, balance_root, state_root, tx_pointer).cmp(&(
+                contract_id_p,
+                utxo_id_p,
+                balance_root_p,
+                state_root_p,
+                tx_pointer_p,
+            )),
+
+            (
+                Self::MessageSigned {
+                    message_id,
+                    sender,
+                    recipient,
+                    amount,
+                    nonce,
+                    witness_index,
+                    data,
+                },
+                Self::MessageSigned {
+                    message_id: message_id_p,
+                    sender: sender_p,
+                    recipient: recipient_p,
+                    amount: amount_p,
+                    nonce: nonce_p,
+                    witness_index: witness_index_p,
+                    data: data_p,
+                },
+            ) => (
+                message_id,
+                sender,
+                recipient,
+                amount,
+                nonce,
+                witness_index,
+                data,
+            )
+                .cmp(&(
+                    message_id_p,
+                    sender_p,
+                    recipient_p,
+                    amount_p,
+                    nonce_p,
+                    witness_index_p,
+                    data_p,
+                )),
+
+            (
+                Self::MessagePredicate {
+                    message_id,
+                    sender,
+                    recipient,
+                    amount,
+                    nonce,
+                    data,
+                    predicate,
+                    predicate_data,
+                },
+                Self::MessagePredicate {
+                    message_id: message_id_p,
+                    sender: sender_p,
+                    recipient: recipient_p,
+                    amount: amount_p,
+                    nonce: nonce_p,
+                    data: data_p,
+                    predicate: predicate_p,
+                    predicate_data: predicate_data_p,
+                },
+            ) => (
+                message_id,
+                sender,
+                recipient,
+                amount,
+                nonce,
+                data,
+                predicate,
+                predicate_data,
+            )
+                .cmp(&(
+                    message_id_p,
+                    sender_p,
+                    recipient_p,
+                    amount_p,
+                    nonce_p,
+                    data_p,
+                    predicate_p,
+                    predicate_data_p,
+                )),
+
+            // Only reached with inputs of the same repr
+            (Self::CoinSigned { .. }, _) | (Self::MessageSigned { .. }, _) => Ordering::Less,
+            _ => Ordering::Greater,
+        }
+    }
+
+    /// Shrink the capacity of the predicate and data buffers of the input to their length.
+    pub fn shrink_to_fit(&mut self) {
+        match self {
+            Input::CoinPredicate {
+                predicate,
+                predicate_data,
+                ..
+            } => {
+                predicate.shrink_to_fit();
+                predicate_data.shrink_to_fit();
+            }
+            Input::MessageSigned { data, .. } => data.shrink_to_fit(),
+            Input::MessagePredicate {
+                data,
+                predicate,
+                predicate_data,
+                ..
+            } => {
+                data.shrink_to_fit();
+                predicate.shrink_to_fit();
+                predicate_data.shrink_to_fit();
+            }
+            Input::CoinSigned { .. } | Input::Contract { .. } => (),
+        }
+    }
+
     pub const fn witness_index(&self) -> Option<u8> {
         match self {
             Input::CoinSigned { witness_index, .. }
@@ -431,6 +728,14 @@ impl Input {
         matches!(self, Input::Contract { .. })
     }
 
+    /// Whether the input is authorized by a signature, and therefore needs a witness slot.
+    ///
+    /// Predicate inputs are authorized by their predicate and contract inputs need no
+    /// authorization.
+    pub const fn requires_witness(&self) -> bool {
+        self.is_coin_signed() | self.is_message_signed()
+    }
+
     pub const fn coin_predicate_offset() -> usize {
         INPUT_COIN_FIXED_SIZE
     }
@@ -522,6 +827,22 @@ impl Input {
         message_id.into()
     }
 
+    /// Heuristic estimation of the gas required to verify the predicate of this input.
+    ///
+    /// This is **not** the result of a VM execution: it charges
+    /// [`GasCosts::predicate_per_byte`](crate::GasCosts::predicate_per_byte) for every byte of
+    /// the padded predicate, meant for wallets that can't run the predicate locally. It doesn't
+    /// account for the instructions executed, so a looping predicate may use more gas than
+    /// estimated. Inputs without a predicate estimate to zero.
+    pub fn estimate_predicate_gas(&self, params: &ConsensusParameters) -> Word {
+        self.input_predicate()
+            .map(|predicate| {
+                (bytes::padded_len(predicate) as Word)
+                    .saturating_mul(params.gas_costs.predicate_per_byte)
+            })
+            .unwrap_or_default()
+    }
+
     pub fn predicate_owner<P>(predicate: P) -> Address
     where
         P: AsRef<[u8]>,
@@ -547,6 +868,16 @@ impl Input {
     }
 }
 
+#[cfg(all(feature = "std", feature = "serde"))]
+impl core::str::FromStr for Input {
+    type Err = serde_json::Error;
+
+    /// Parse the JSON representation of the input.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
 #[cfg(feature = "std")]
 impl io::Read for Input {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
@@ -733,6 +1064,8 @@ impl io::Write for Input {
                 let (predicate_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
                 let (predicate_data_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
 
+                check_predicate_data_len(predicate_len, predicate_data_len)?;
+
                 let (size, predicate, buf) = bytes::restore_raw_bytes(buf, predicate_len)?;
                 n += size;
 
@@ -817,6 +1150,8 @@ impl io::Write for Input {
                 let (predicate_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
                 let (predicate_data_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
 
+                check_predicate_data_len(predicate_len, predicate_data_len)?;
+
                 let (size, data, buf) = bytes::restore_raw_bytes(buf, data_len)?;
                 n += size;
 
@@ -862,3 +1197,445 @@ impl io::Write for Input {
         Ok(())
     }
 }
+
+#[cfg(feature = "std")]
+impl Input {
+    /// Decode the input at the start of `bytes`, returning it with the number of bytes it
+    /// occupies.
+    ///
+    /// Sequences of inputs of varying sizes can be walked with it, without measuring every
+    /// decoded input again with [`SizedBytes::serialized_size`].
+    pub fn decode_with_len(bytes: &[u8]) -> io::Result<(Self, usize)> {
+        let mut input = Self::default();
+        let n = io::Write::write(&mut input, bytes)?;
+
+        Ok((input, n))
+    }
+}
+
+/// An input without predicate can't carry predicate data.
+#[cfg(feature = "std")]
+fn check_predicate_data_len(predicate_len: usize, predicate_data_len: usize) -> io::Result<()> {
+    if predicate_len == 0 && predicate_data_len != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "The provided predicate data requires a non-empty predicate!",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_predicate_gas_grows_with_predicate_len() {
+        let params = ConsensusParameters::DEFAULT.with_gas_costs(crate::GasCosts {
+            predicate_per_byte: 2,
+            ..Default::default()
+        });
+
+        let predicate_input = |predicate: Vec<u8>| {
+            let owner = Input::predicate_owner(&predicate);
+
+            Input::coin_predicate(
+                Default::default(),
+                owner,
+                0,
+                Default::default(),
+                Default::default(),
+                0,
+                predicate,
+                vec![],
+            )
+        };
+
+        let short = predicate_input(vec![0xaa; 16]).estimate_predicate_gas(&params);
+        let long = predicate_input(vec![0xaa; 256]).estimate_predicate_gas(&params);
+
+        assert_eq!(32, short);
+        assert_eq!(512, long);
+
+        let signed = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+        );
+
+        assert_eq!(0, signed.estimate_predicate_gas(&params));
+    }
+
+    #[test]
+    fn decode_rejects_predicate_data_without_predicate() {
+        use fuel_types::bytes::{Deserializable, SerializableVec};
+
+        let mut coin = Input::coin_predicate(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            vec![],
+            vec![0xfa; 8],
+        );
+
+        let err = Input::from_bytes(&coin.to_bytes()).expect_err("Coin should be rejected");
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+        let mut message = Input::message_predicate(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+            vec![],
+            vec![],
+            vec![0xfa; 8],
+        );
+
+        let err = Input::from_bytes(&message.to_bytes()).expect_err("Message should be rejected");
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    }
+
+    #[test]
+    fn serialized_size_matches_consts() {
+        use fuel_types::bytes::SerializableVec;
+
+        let coin_predicate = |predicate: Vec<u8>, predicate_data: Vec<u8>| {
+            Input::coin_predicate(
+                Default::default(),
+                Default::default(),
+                0,
+                Default::default(),
+                Default::default(),
+                0,
+                predicate,
+                predicate_data,
+            )
+        };
+        let message_signed = |data: Vec<u8>| {
+            Input::message_signed(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+                0,
+                data,
+            )
+        };
+        let message_predicate = |data: Vec<u8>, predicate: Vec<u8>, predicate_data: Vec<u8>| {
+            Input::message_predicate(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+                data,
+                predicate,
+                predicate_data,
+            )
+        };
+
+        let cases = [
+            (
+                INPUT_COIN_FIXED_SIZE,
+                Input::coin_signed(
+                    Default::default(),
+                    Default::default(),
+                    0,
+                    Default::default(),
+                    Default::default(),
+                    0,
+                    0,
+                ),
+            ),
+            (INPUT_COIN_FIXED_SIZE, coin_predicate(vec![], vec![])),
+            (
+                INPUT_COIN_FIXED_SIZE + 8,
+                coin_predicate(vec![0xaa; 8], vec![]),
+            ),
+            (
+                INPUT_COIN_FIXED_SIZE + 16 + 8,
+                coin_predicate(vec![0xaa; 9], vec![0xbb; 1]),
+            ),
+            (
+                INPUT_CONTRACT_SIZE,
+                Input::contract(
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ),
+            ),
+            (INPUT_MESSAGE_FIXED_SIZE, message_signed(vec![])),
+            (INPUT_MESSAGE_FIXED_SIZE + 8, message_signed(vec![0xcc; 3])),
+            (
+                INPUT_MESSAGE_FIXED_SIZE,
+                message_predicate(vec![], vec![], vec![]),
+            ),
+            (
+                INPUT_MESSAGE_FIXED_SIZE + 16 + 8 + 24,
+                message_predicate(vec![0xcc; 16], vec![0xaa; 7], vec![0xbb; 17]),
+            ),
+        ];
+
+        for (size, mut input) in cases {
+            assert_eq!(size, input.serialized_size(), "{:?}", input);
+            assert_eq!(size, input.to_bytes().len(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn requires_witness_only_for_signed_inputs() {
+        let coin_signed = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+        );
+        let coin_predicate = Input::coin_predicate(
+            Default::default(),
+            Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            0,
+            vec![0xaa; 8],
+            vec![],
+        );
+        let contract = Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        let message_signed = Input::message_signed(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+            0,
+            vec![],
+        );
+        let message_predicate = Input::message_predicate(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+            vec![],
+            vec![0xaa; 8],
+            vec![],
+        );
+
+        assert!(coin_signed.requires_witness());
+        assert!(message_signed.requires_witness());
+        assert!(!coin_predicate.requires_witness());
+        assert!(!contract.requires_witness());
+        assert!(!message_predicate.requires_witness());
+    }
+
+    #[test]
+    fn coin_predicate_derive_owner_sets_valid_owner() {
+        let predicate = vec![0xaa; 24];
+
+        let input = Input::coin_predicate_derive_owner(
+            Default::default(),
+            10,
+            Default::default(),
+            Default::default(),
+            0,
+            predicate.clone(),
+            vec![0xbb; 8],
+        );
+
+        let owner = input.input_owner().expect("coin input has an owner");
+        assert!(Input::is_predicate_owner_valid(owner, &predicate));
+        assert_eq!(Some(predicate.as_slice()), input.input_predicate());
+    }
+
+    #[test]
+    fn amount_mut_keeps_variant() {
+        let mut input = Input::coin_predicate(
+            Default::default(),
+            Default::default(),
+            10,
+            Default::default(),
+            Default::default(),
+            0,
+            vec![0xaa; 8],
+            vec![0xbb; 8],
+        );
+
+        *input.amount_mut().expect("coin input has an amount") = 5;
+        assert!(input.is_coin_predicate());
+        assert_eq!(Some(5), input.amount());
+        assert_eq!(Some(&[0xaa; 8][..]), input.input_predicate());
+        assert_eq!(Some(&[0xbb; 8][..]), input.input_predicate_data());
+
+        let mut input = Input::message_signed(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            10,
+            0,
+            0,
+            vec![0xcc; 8],
+        );
+
+        *input.amount_mut().expect("message input has an amount") = 5;
+        assert!(input.is_message_signed());
+        assert_eq!(Some(5), input.amount());
+
+        let mut input = Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        assert_eq!(None, input.amount_mut());
+    }
+
+    #[test]
+    fn decode_with_len_walks_input_sequence() {
+        use fuel_types::bytes::SerializableVec;
+
+        let mut inputs = [
+            Input::coin_predicate(
+                Default::default(),
+                Default::default(),
+                10,
+                Default::default(),
+                Default::default(),
+                0,
+                vec![0xaa; 12],
+                vec![0xbb; 3],
+            ),
+            Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+            Input::message_predicate(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                10,
+                0,
+                vec![0xcc; 5],
+                vec![0xaa; 40],
+                vec![],
+            ),
+        ];
+
+        let bytes = inputs
+            .iter_mut()
+            .flat_map(|input| input.to_bytes())
+            .collect::<Vec<u8>>();
+
+        let mut buf = bytes.as_slice();
+        for input in inputs.iter() {
+            let (decoded, n) = Input::decode_with_len(buf).expect("failed to decode input");
+
+            assert_eq!(input, &decoded);
+            assert_eq!(input.serialized_size(), n);
+
+            buf = &buf[n..];
+        }
+        assert!(buf.is_empty());
+
+        let truncated = &bytes[..inputs[0].serialized_size() - 1];
+        assert!(Input::decode_with_len(truncated).is_err());
+    }
+
+    #[test]
+    fn canonical_cmp_is_independent_of_insertion_order() {
+        let utxo_id = |b: u8| UtxoId::new([b; 32].into(), 0);
+        let coin = |b: u8| {
+            Input::coin_signed(
+                utxo_id(b),
+                [0xaa; 32].into(),
+                10,
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+            )
+        };
+        let coin_predicate = |b: u8| {
+            Input::coin_predicate(
+                utxo_id(b),
+                [0xaa; 32].into(),
+                10,
+                Default::default(),
+                Default::default(),
+                0,
+                vec![0xfa; 8],
+                vec![],
+            )
+        };
+        // Contracts are sorted by contract id, not utxo id
+        let contract = |b: u8| {
+            Input::contract(
+                utxo_id(0xff - b),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                [b; 32].into(),
+            )
+        };
+        let message = |b: u8| {
+            Input::message_signed(
+                [b; 32].into(),
+                Default::default(),
+                Default::default(),
+                10,
+                0,
+                0,
+                vec![],
+            )
+        };
+
+        let expected = vec![
+            coin(1),
+            coin_predicate(1),
+            coin(2),
+            contract(1),
+            contract(2),
+            message(1),
+            message(2),
+        ];
+
+        let mut inputs = expected.clone();
+        inputs.reverse();
+        inputs.sort();
+        assert_eq!(expected, inputs);
+
+        inputs.rotate_left(3);
+        inputs.sort_by(Input::canonical_cmp);
+        assert_eq!(expected, inputs);
+
+        // Inputs with the same keys are still ordered consistently with `Eq`
+        let mut other = coin(1);
+        *other.amount_mut().expect("coin input") = 11;
+        assert_eq!(Ordering::Less, coin(1).cmp(&other));
+        assert_eq!(Ordering::Equal, coin(1).cmp(&coin(1)));
+    }
+}
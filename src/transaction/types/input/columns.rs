@@ -0,0 +1,199 @@
+use super::{Input, InputRepr};
+use crate::{TxPointer, UtxoId};
+
+use fuel_types::{Address, AssetId, Bytes32, ContractId, MessageId, Word};
+
+use alloc::vec::Vec;
+
+/// Flat row of an [`Input`], for columnar storage.
+///
+/// Every column that isn't a field of the input variant is `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InputColumns {
+    pub repr: InputRepr,
+    pub utxo_id: Option<UtxoId>,
+    pub owner: Option<Address>,
+    pub amount: Option<Word>,
+    pub asset_id: Option<AssetId>,
+    pub tx_pointer: Option<TxPointer>,
+    pub witness_index: Option<u8>,
+    pub maturity: Option<Word>,
+    pub predicate: Option<Vec<u8>>,
+    pub predicate_data: Option<Vec<u8>>,
+    pub balance_root: Option<Bytes32>,
+    pub state_root: Option<Bytes32>,
+    pub contract_id: Option<ContractId>,
+    pub message_id: Option<MessageId>,
+    pub sender: Option<Address>,
+    pub recipient: Option<Address>,
+    pub nonce: Option<Word>,
+    pub data: Option<Vec<u8>>,
+}
+
+impl Input {
+    /// Map the input to a flat row, with a column per field of every variant.
+    pub fn to_columns(&self) -> InputColumns {
+        let asset_id = match self {
+            Input::CoinSigned { asset_id, .. } | Input::CoinPredicate { asset_id, .. } => {
+                Some(*asset_id)
+            }
+            _ => None,
+        };
+
+        InputColumns {
+            repr: self.repr(),
+            utxo_id: self.utxo_id().copied(),
+            owner: self.input_owner().copied(),
+            amount: self.amount(),
+            asset_id,
+            tx_pointer: self.tx_pointer().copied(),
+            witness_index: self.witness_index(),
+            maturity: self.maturity(),
+            predicate: self.input_predicate().map(<[u8]>::to_vec),
+            predicate_data: self.input_predicate_data().map(<[u8]>::to_vec),
+            balance_root: self.balance_root().copied(),
+            state_root: self.state_root().copied(),
+            contract_id: self.contract_id().copied(),
+            message_id: self.message_id().copied(),
+            sender: self.sender().copied(),
+            recipient: self.recipient().copied(),
+            nonce: self.nonce(),
+            data: self.input_data().map(<[u8]>::to_vec),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_columns_populates_variant_fields() {
+        let utxo_id = UtxoId::new([0x01; 32].into(), 2);
+        let owner: Address = [0x03; 32].into();
+        let asset_id: AssetId = [0x04; 32].into();
+        let tx_pointer = TxPointer::new(5, 6);
+
+        let columns =
+            Input::coin_signed(utxo_id, owner, 7, asset_id, tx_pointer, 8, 9).to_columns();
+        assert_eq!(
+            InputColumns {
+                repr: InputRepr::Coin,
+                utxo_id: Some(utxo_id),
+                owner: Some(owner),
+                amount: Some(7),
+                asset_id: Some(asset_id),
+                tx_pointer: Some(tx_pointer),
+                witness_index: Some(8),
+                maturity: Some(9),
+                predicate: None,
+                predicate_data: None,
+                balance_root: None,
+                state_root: None,
+                contract_id: None,
+                message_id: None,
+                sender: None,
+                recipient: None,
+                nonce: None,
+                data: None,
+            },
+            columns
+        );
+
+        let columns = Input::coin_predicate(
+            utxo_id,
+            owner,
+            7,
+            asset_id,
+            tx_pointer,
+            9,
+            vec![0xfa],
+            vec![0xfb],
+        )
+        .to_columns();
+        assert_eq!(InputRepr::Coin, columns.repr);
+        assert_eq!(None, columns.witness_index);
+        assert_eq!(Some(vec![0xfa]), columns.predicate);
+        assert_eq!(Some(vec![0xfb]), columns.predicate_data);
+        assert_eq!(Some(asset_id), columns.asset_id);
+        assert_eq!(None, columns.data);
+
+        let balance_root: Bytes32 = [0x0a; 32].into();
+        let state_root: Bytes32 = [0x0b; 32].into();
+        let contract_id: ContractId = [0x0c; 32].into();
+
+        let columns = Input::contract(utxo_id, balance_root, state_root, tx_pointer, contract_id)
+            .to_columns();
+        assert_eq!(
+            InputColumns {
+                repr: InputRepr::Contract,
+                utxo_id: Some(utxo_id),
+                owner: None,
+                amount: None,
+                asset_id: None,
+                tx_pointer: Some(tx_pointer),
+                witness_index: None,
+                maturity: None,
+                predicate: None,
+                predicate_data: None,
+                balance_root: Some(balance_root),
+                state_root: Some(state_root),
+                contract_id: Some(contract_id),
+                message_id: None,
+                sender: None,
+                recipient: None,
+                nonce: None,
+                data: None,
+            },
+            columns
+        );
+
+        let sender: Address = [0x0d; 32].into();
+        let recipient: Address = [0x0e; 32].into();
+        let message =
+            Input::message_signed([0x0f; 32].into(), sender, recipient, 10, 11, 12, vec![0xfc]);
+        let message_id = *message.message_id().expect("message input");
+
+        assert_eq!(
+            InputColumns {
+                repr: InputRepr::Message,
+                utxo_id: None,
+                owner: None,
+                amount: Some(10),
+                asset_id: None,
+                tx_pointer: None,
+                witness_index: Some(12),
+                maturity: None,
+                predicate: None,
+                predicate_data: None,
+                balance_root: None,
+                state_root: None,
+                contract_id: None,
+                message_id: Some(message_id),
+                sender: Some(sender),
+                recipient: Some(recipient),
+                nonce: Some(11),
+                data: Some(vec![0xfc]),
+            },
+            message.to_columns()
+        );
+
+        let columns = Input::message_predicate(
+            [0x0f; 32].into(),
+            sender,
+            recipient,
+            10,
+            11,
+            vec![0xfc],
+            vec![0xfa],
+            vec![0xfb],
+        )
+        .to_columns();
+        assert_eq!(InputRepr::Message, columns.repr);
+        assert_eq!(None, columns.witness_index);
+        assert_eq!(Some(vec![0xfa]), columns.predicate);
+        assert_eq!(Some(vec![0xfb]), columns.predicate_data);
+        assert_eq!(Some(vec![0xfc]), columns.data);
+        assert_eq!(Some(recipient), columns.recipient);
+    }
+}
@@ -9,7 +9,7 @@ pub(super) const INPUT_COIN_ASSET_ID_OFFSET: usize = INPUT_COIN_OWNER_OFFSET
     + Address::LEN // Owner
     + WORD_SIZE; // Amount
 pub(super) const INPUT_COIN_TX_POINTER_OFFSET: usize = INPUT_COIN_ASSET_ID_OFFSET + AssetId::LEN; // AssetId
-pub(super) const INPUT_COIN_FIXED_SIZE: usize = INPUT_COIN_TX_POINTER_OFFSET
+pub const INPUT_COIN_FIXED_SIZE: usize = INPUT_COIN_TX_POINTER_OFFSET
     + TxPointer::LEN // TxPointer
     + WORD_SIZE // Witness index
     + WORD_SIZE // Maturity
@@ -23,13 +23,13 @@ pub(super) const INPUT_CONTRACT_TX_POINTER_OFFSET: usize =
     INPUT_CONTRACT_STATE_ROOT_OFFSET + Bytes32::LEN; // State root
 pub(super) const INPUT_CONTRACT_ID_OFFSET: usize =
     INPUT_CONTRACT_TX_POINTER_OFFSET + TxPointer::LEN; // TxPointer
-pub(super) const INPUT_CONTRACT_SIZE: usize = INPUT_CONTRACT_ID_OFFSET + ContractId::LEN; // Contract address
+pub const INPUT_CONTRACT_SIZE: usize = INPUT_CONTRACT_ID_OFFSET + ContractId::LEN; // Contract address
 
 pub(super) const INPUT_MESSAGE_ID_OFFSET: usize = WORD_SIZE; // Identifier
 pub(super) const INPUT_MESSAGE_SENDER_OFFSET: usize = INPUT_MESSAGE_ID_OFFSET + MessageId::LEN; // message_id
 pub(super) const INPUT_MESSAGE_RECIPIENT_OFFSET: usize = INPUT_MESSAGE_SENDER_OFFSET + Address::LEN; // sender
 
-pub(super) const INPUT_MESSAGE_FIXED_SIZE: usize = INPUT_MESSAGE_RECIPIENT_OFFSET
+pub const INPUT_MESSAGE_FIXED_SIZE: usize = INPUT_MESSAGE_RECIPIENT_OFFSET
     + Address::LEN // recipient
     + WORD_SIZE //amount
     + WORD_SIZE // nonce
@@ -0,0 +1,101 @@
+use super::Input;
+
+use fuel_types::Address;
+
+/// Everything the VM needs to execute the predicate of an input.
+///
+/// Inputs don't declare the gas used by their predicates, so it isn't part of the context;
+/// [`Input::estimate_predicate_gas`] provides an estimate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PredicateContext<'a> {
+    /// Owner of the input, i.e. the address derived from the predicate.
+    pub owner: &'a Address,
+    /// Bytecode of the predicate.
+    pub predicate: &'a [u8],
+    /// Data passed to the predicate.
+    pub predicate_data: &'a [u8],
+}
+
+impl Input {
+    /// Predicate of the input along with its data and owner, if the input is a predicate.
+    pub fn predicate_context(&self) -> Option<PredicateContext<'_>> {
+        match self {
+            Input::CoinPredicate {
+                owner,
+                predicate,
+                predicate_data,
+                ..
+            }
+            | Input::MessagePredicate {
+                recipient: owner,
+                predicate,
+                predicate_data,
+                ..
+            } => Some(PredicateContext {
+                owner,
+                predicate,
+                predicate_data,
+            }),
+
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicate_context_of_predicate_inputs() {
+        let predicate = vec![0xfa; 16];
+        let predicate_data = vec![0xfb; 8];
+
+        let input = Input::coin_predicate_derive_owner(
+            Default::default(),
+            10,
+            Default::default(),
+            Default::default(),
+            0,
+            predicate.clone(),
+            predicate_data.clone(),
+        );
+
+        let context = input.predicate_context().expect("predicate input");
+        assert_eq!(input.input_owner(), Some(context.owner));
+        assert_eq!(predicate.as_slice(), context.predicate);
+        assert_eq!(predicate_data.as_slice(), context.predicate_data);
+        assert!(Input::is_predicate_owner_valid(
+            context.owner,
+            context.predicate
+        ));
+
+        let recipient = Address::from([0xfc; 32]);
+        let input = Input::message_predicate(
+            Default::default(),
+            Default::default(),
+            recipient,
+            10,
+            0,
+            vec![],
+            predicate.clone(),
+            vec![],
+        );
+
+        let context = input.predicate_context().expect("predicate input");
+        assert_eq!(&recipient, context.owner);
+        assert_eq!(predicate.as_slice(), context.predicate);
+        assert!(context.predicate_data.is_empty());
+
+        let input = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            10,
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+        );
+        assert_eq!(None, input.predicate_context());
+    }
+}
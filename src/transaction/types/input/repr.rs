@@ -7,6 +7,20 @@ use fuel_types::Word;
 #[cfg(feature = "std")]
 use std::io;
 
+/// Wire discriminant of an [`Input`].
+///
+/// Every representation is a fixed-size part, described by the constants of `consts`,
+/// followed by its variable-length fields. Each variable-length field stores its length as a
+/// word in the fixed-size part, while its bytes are appended, padded to the word size, after
+/// the fixed-size part in declaration order; message `data` is the reference layout.
+///
+/// Adding such a field to a representation, e.g. arbitrary `data` attached to coins, means:
+/// - adding its length word to the fixed size and shifting the offsets that follow it;
+/// - exposing its offset here, as [`Self::data_offset`] does for messages;
+/// - accounting its padded length in the `SizedBytes` implementation of [`Input`];
+/// - encoding and decoding it in the `io::Read` and `io::Write` implementations of [`Input`].
+///
+/// Since the layout is part of the protocol, it must not depend on crate features.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum InputRepr {
     Coin = 0x00,
@@ -132,3 +146,82 @@ impl TryFrom<Word> for InputRepr {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_input_maps_to_spec_discriminant() {
+        let inputs = [
+            Input::coin_signed(
+                Default::default(),
+                Default::default(),
+                0,
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+            ),
+            Input::coin_predicate(
+                Default::default(),
+                Default::default(),
+                0,
+                Default::default(),
+                Default::default(),
+                0,
+                vec![0xff],
+                vec![],
+            ),
+            Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+            Input::message_signed(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+                0,
+                vec![],
+            ),
+            Input::message_predicate(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+                vec![],
+                vec![0xff],
+                vec![],
+            ),
+        ];
+
+        for input in inputs.iter() {
+            // Exhaustive match without wildcard so adding or reordering a variant of `Input`
+            // requires revisiting the expected wire discriminant here.
+            let (expected, discriminant) = match input {
+                Input::CoinSigned { .. } => (InputRepr::Coin, 0x00),
+                Input::CoinPredicate { .. } => (InputRepr::Coin, 0x00),
+                Input::Contract { .. } => (InputRepr::Contract, 0x01),
+                Input::MessageSigned { .. } => (InputRepr::Message, 0x02),
+                Input::MessagePredicate { .. } => (InputRepr::Message, 0x02),
+            };
+
+            let repr = InputRepr::from_input(input);
+
+            assert_eq!(expected, repr);
+            assert_eq!(discriminant, repr.clone() as u64);
+
+            #[cfg(feature = "std")]
+            assert_eq!(
+                repr,
+                InputRepr::try_from(discriminant).expect("valid discriminant")
+            );
+        }
+    }
+}
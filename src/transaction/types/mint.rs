@@ -5,13 +5,13 @@ use crate::transaction::{
 use crate::{CheckError, ConsensusParameters, Output, TxPointer};
 use derivative::Derivative;
 use fuel_types::bytes::{SizedBytes, WORD_SIZE};
-use fuel_types::{Bytes32, Word};
+use fuel_types::{Address, AssetId, Bytes32, Word};
 
 #[cfg(feature = "std")]
 use std::io;
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 
 #[cfg(feature = "std")]
 use fuel_types::bytes::{self, Deserializable, SerializableVec};
@@ -88,6 +88,49 @@ impl crate::UniqueIdentifier for Mint {
     }
 }
 
+impl Mint {
+    /// Build the coinbase transaction paying the collected `fees` to `to`, with a coin output
+    /// per asset.
+    ///
+    /// Assets without fees are skipped, since `Mint` coin outputs can't be zero.
+    pub fn from_fees(tx_pointer: TxPointer, to: Address, fees: &BTreeMap<AssetId, Word>) -> Self {
+        let outputs = fees
+            .iter()
+            .filter(|(_, amount)| **amount > 0)
+            .map(|(asset_id, amount)| Output::coin(to, *amount, *asset_id))
+            .collect();
+
+        crate::Transaction::mint(tx_pointer, outputs)
+    }
+
+    /// Check that the total amount minted of every asset doesn't exceed `cap`.
+    ///
+    /// It isn't part of [`Checkable::check`], since the cap is a policy of the block producer.
+    pub fn check_mint_cap(&self, cap: Word) -> Result<(), CheckError> {
+        let mut minted = BTreeMap::<AssetId, Word>::new();
+
+        self.outputs()
+            .iter()
+            .filter_map(|output| match output {
+                Output::Coin {
+                    asset_id, amount, ..
+                } => Some((asset_id, amount)),
+                _ => None,
+            })
+            .try_for_each(|(asset_id, amount)| {
+                let total = minted.entry(*asset_id).or_default();
+
+                match total.checked_add(*amount) {
+                    Some(sum) if sum <= cap => {
+                        *total = sum;
+                        Ok(())
+                    }
+                    _ => Err(CheckError::TransactionMintExceedsCap { asset: *asset_id }),
+                }
+            })
+    }
+}
+
 impl Checkable for Mint {
     #[cfg(feature = "std")]
     fn check_signatures(&self) -> Result<(), CheckError> {
@@ -107,8 +150,15 @@ impl Checkable for Mint {
         }
 
         let mut assets = Vec::new();
-        for output in self.outputs() {
-            if let Output::Coin { asset_id, .. } = output {
+        for (index, output) in self.outputs().iter().enumerate() {
+            if let Output::Coin {
+                asset_id, amount, ..
+            } = output
+            {
+                if *amount == 0 {
+                    return Err(CheckError::TransactionMintZeroCoinOutput { index });
+                }
+
                 if assets.contains(asset_id) {
                     return Err(CheckError::TransactionOutputCoinAssetIdDuplicated(
                         *asset_id,
@@ -148,22 +198,25 @@ impl SizedBytes for Mint {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub mod checked {
-    use crate::{
-        Cacheable, CheckError, Checkable, Checked, ConsensusParameters, IntoChecked, Mint,
-    };
+    #[cfg(feature = "std")]
+    use crate::Cacheable;
+    use crate::{CheckError, Checkable, Checked, ConsensusParameters, IntoChecked, Mint};
     use fuel_types::Word;
 
     impl IntoChecked for Mint {
         type Metadata = ();
 
+        #[cfg_attr(not(feature = "std"), allow(unused_mut))]
         fn into_checked_basic(
             mut self,
             block_height: Word,
             params: &ConsensusParameters,
         ) -> Result<Checked<Self>, CheckError> {
+            #[cfg(feature = "std")]
             self.precompute();
+
             self.check_without_signatures(block_height, params)?;
 
             Ok(Checked::basic(self, ()))
@@ -287,6 +340,8 @@ impl io::Write for Mint {
         let buf = &buf[tx_pointer.serialized_size()..];
         let (outputs_len, mut buf) = unsafe { bytes::restore_usize_unchecked(buf) };
 
+        super::check_elements_len(buf, &[outputs_len])?;
+
         let mut outputs = vec![Output::default(); outputs_len];
         for output in outputs.iter_mut() {
             let output_len = output.write(buf)?;
@@ -10,15 +10,25 @@ use fuel_types::bytes::{SizedBytes, WORD_SIZE};
 #[cfg(feature = "std")]
 use std::io;
 
+mod columns;
 mod consts;
 mod repr;
 
-use consts::*;
+#[cfg(feature = "serde")]
+mod tagged;
 
+pub use columns::OutputColumns;
+pub use consts::{
+    OUTPUT_CCV_SIZE, OUTPUT_CONTRACT_CREATED_SIZE, OUTPUT_CONTRACT_SIZE, OUTPUT_MESSAGE_SIZE,
+};
 pub use repr::OutputRepr;
 
+#[cfg(feature = "serde")]
+pub use tagged::TaggedOutput;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Output {
     Coin {
         to: Address,
@@ -32,9 +42,17 @@ pub enum Output {
         state_root: Bytes32,
     },
 
+    /// Message emitted by the script.
+    ///
+    /// Every field is set by the VM when the message is sent, so the message id can be
+    /// recomputed from the output with the sender and the data it commits to.
     Message {
         recipient: Address,
         amount: Word,
+        #[cfg_attr(feature = "serde", serde(default))]
+        nonce: Bytes32,
+        #[cfg_attr(feature = "serde", serde(default))]
+        digest: Bytes32,
     },
 
     Change {
@@ -99,8 +117,14 @@ impl Output {
         }
     }
 
+    /// Message output with a zeroed nonce and digest, as they are only known after execution.
     pub const fn message(recipient: Address, amount: Word) -> Self {
-        Self::Message { recipient, amount }
+        Self::Message {
+            recipient,
+            amount,
+            nonce: Bytes32::zeroed(),
+            digest: Bytes32::zeroed(),
+        }
     }
 
     pub const fn change(to: Address, amount: Word, asset_id: AssetId) -> Self {
@@ -135,6 +159,36 @@ impl Output {
         }
     }
 
+    /// Mutable reference to the asset id of coin, change and variable outputs.
+    pub fn asset_id_mut(&mut self) -> Option<&mut AssetId> {
+        match self {
+            Output::Coin { asset_id, .. }
+            | Output::Change { asset_id, .. }
+            | Output::Variable { asset_id, .. } => Some(asset_id),
+            _ => None,
+        }
+    }
+
+    /// Set the asset of the output to [`AssetId::BASE`], as required for the change outputs of
+    /// `Create` transactions.
+    ///
+    /// Fails if the output holds no asset.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_base_asset(&mut self) -> Result<(), ()> {
+        self.set_base_asset_with_base(&AssetId::BASE)
+    }
+
+    /// Set the asset of the output to `base_asset_id`, for chains with a custom base asset.
+    ///
+    /// Fails if the output holds no asset.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_base_asset_with_base(&mut self, base_asset_id: &AssetId) -> Result<(), ()> {
+        let asset_id = self.asset_id_mut().ok_or(())?;
+        *asset_id = *base_asset_id;
+
+        Ok(())
+    }
+
     pub const fn to(&self) -> Option<&Address> {
         match self {
             Output::Coin { to, .. } | Output::Change { to, .. } | Output::Variable { to, .. } => {
@@ -191,6 +245,22 @@ impl Output {
         }
     }
 
+    /// Nonce of message outputs, as computed by [`Output::message_nonce`].
+    pub const fn nonce(&self) -> Option<&Bytes32> {
+        match self {
+            Output::Message { nonce, .. } => Some(nonce),
+            _ => None,
+        }
+    }
+
+    /// Digest of the data of message outputs, as computed by [`Output::message_digest`].
+    pub const fn digest(&self) -> Option<&Bytes32> {
+        match self {
+            Output::Message { digest, .. } => Some(digest),
+            _ => None,
+        }
+    }
+
     pub const fn is_coin(&self) -> bool {
         matches!(self, Self::Coin { .. })
     }
@@ -249,9 +319,16 @@ impl Output {
                 mem::take(state_root);
             }
 
-            Output::Message { recipient, amount } => {
+            Output::Message {
+                recipient,
+                amount,
+                nonce,
+                digest,
+            } => {
                 mem::take(recipient);
                 mem::take(amount);
+                mem::take(nonce);
+                mem::take(digest);
             }
 
             Output::Change { amount, .. } => {
@@ -273,13 +350,66 @@ impl Output {
         }
     }
 
+    /// Whether both outputs are the same variant with the same stable fields.
+    ///
+    /// Fields that change at runtime, such as the amounts, the contract roots and the
+    /// destination of variable outputs, are ignored. It allows comparing an output before and
+    /// after the execution of the transaction.
+    pub fn eq_structure(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Coin {
+                    to: a, asset_id: x, ..
+                },
+                Self::Coin {
+                    to: b, asset_id: y, ..
+                },
+            )
+            | (
+                Self::Change {
+                    to: a, asset_id: x, ..
+                },
+                Self::Change {
+                    to: b, asset_id: y, ..
+                },
+            ) => a == b && x == y,
+
+            (Self::Contract { input_index: a, .. }, Self::Contract { input_index: b, .. }) => {
+                a == b
+            }
+
+            (Self::Message { .. }, Self::Message { .. })
+            | (Self::Variable { .. }, Self::Variable { .. }) => true,
+
+            (
+                Self::ContractCreated {
+                    contract_id: a,
+                    state_root: x,
+                },
+                Self::ContractCreated {
+                    contract_id: b,
+                    state_root: y,
+                },
+            ) => a == b && x == y,
+
+            _ => false,
+        }
+    }
+
     /// Prepare the output for VM initialization for script execution
     #[cfg(feature = "std")]
     pub fn prepare_init_script(&mut self) {
         match self {
-            Output::Message { recipient, amount } => {
+            Output::Message {
+                recipient,
+                amount,
+                nonce,
+                digest,
+            } => {
                 mem::take(recipient);
                 mem::take(amount);
+                mem::take(nonce);
+                mem::take(digest);
             }
 
             Output::Change { amount, .. } => {
@@ -306,6 +436,16 @@ impl Output {
     }
 }
 
+#[cfg(all(feature = "std", feature = "serde"))]
+impl core::str::FromStr for Output {
+    type Err = serde_json::Error;
+
+    /// Parse the JSON representation of the output.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
 #[cfg(feature = "std")]
 impl io::Read for Output {
     fn read(&mut self, mut buf: &mut [u8]) -> io::Result<usize> {
@@ -339,10 +479,17 @@ impl io::Read for Output {
                 bytes::store_array_unchecked(buf, asset_id);
             }
 
-            Self::Message { recipient, amount } => {
+            Self::Message {
+                recipient,
+                amount,
+                nonce,
+                digest,
+            } => {
                 buf = bytes::store_array_unchecked(buf, recipient);
+                buf = bytes::store_number_unchecked(buf, *amount);
+                buf = bytes::store_array_unchecked(buf, nonce);
 
-                bytes::store_number_unchecked(buf, *amount);
+                bytes::store_array_unchecked(buf, digest);
             }
 
             Self::Contract {
@@ -439,11 +586,20 @@ impl io::Write for Output {
             OutputRepr::Message => {
                 // Safety: buf len is checked
                 let (recipient, buf) = unsafe { bytes::restore_array_unchecked(buf) };
-                let (amount, _) = unsafe { bytes::restore_number_unchecked(buf) };
+                let (amount, buf) = unsafe { bytes::restore_number_unchecked(buf) };
+                let (nonce, buf) = unsafe { bytes::restore_array_unchecked(buf) };
+                let (digest, _) = unsafe { bytes::restore_array_unchecked(buf) };
 
                 let recipient = recipient.into();
-
-                *self = Self::Message { recipient, amount };
+                let nonce = nonce.into();
+                let digest = digest.into();
+
+                *self = Self::Message {
+                    recipient,
+                    amount,
+                    nonce,
+                    digest,
+                };
 
                 Ok(OUTPUT_MESSAGE_SIZE)
             }
@@ -488,3 +644,73 @@ impl io::Write for Output {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    use fuel_types::bytes::SerializableVec;
+
+    #[test]
+    fn serialized_size_matches_consts() {
+        let cases = [
+            (
+                OUTPUT_CCV_SIZE,
+                Output::coin(Default::default(), 0, Default::default()),
+            ),
+            (
+                OUTPUT_CONTRACT_SIZE,
+                Output::contract(0, Default::default(), Default::default()),
+            ),
+            (OUTPUT_MESSAGE_SIZE, Output::message(Default::default(), 0)),
+            (
+                OUTPUT_CCV_SIZE,
+                Output::change(Default::default(), 0, Default::default()),
+            ),
+            (
+                OUTPUT_CCV_SIZE,
+                Output::variable(Default::default(), 0, Default::default()),
+            ),
+            (
+                OUTPUT_CONTRACT_CREATED_SIZE,
+                Output::contract_created(Default::default(), Default::default()),
+            ),
+        ];
+
+        for (size, mut output) in cases {
+            assert_eq!(size, output.serialized_size(), "{:?}", output);
+            assert_eq!(size, output.to_bytes().len(), "{:?}", output);
+        }
+    }
+
+    #[test]
+    fn eq_structure_ignores_runtime_fields() {
+        let to = Address::from([0x01; 32]);
+        let asset_id = AssetId::from([0x02; 32]);
+
+        let before = Output::coin(to, 10, asset_id);
+        let after = Output::coin(to, 20, asset_id);
+        assert_ne!(before, after);
+        assert!(before.eq_structure(&after));
+
+        assert!(!before.eq_structure(&Output::coin(Default::default(), 10, asset_id)));
+        assert!(!before.eq_structure(&Output::coin(to, 10, Default::default())));
+        assert!(!before.eq_structure(&Output::change(to, 10, asset_id)));
+
+        assert!(Output::contract(1, [0x03; 32].into(), [0x04; 32].into())
+            .eq_structure(&Output::contract(1, Default::default(), Default::default())));
+        assert!(!Output::contract(1, Default::default(), Default::default())
+            .eq_structure(&Output::contract(2, Default::default(), Default::default())));
+
+        assert!(Output::variable(Default::default(), 0, Default::default())
+            .eq_structure(&Output::variable(to, 10, asset_id)));
+        assert!(Output::message(Default::default(), 0).eq_structure(&Output::message(to, 10)));
+
+        let created = Output::contract_created([0x05; 32].into(), [0x06; 32].into());
+        assert!(created.eq_structure(&created));
+        assert!(!created.eq_structure(&Output::contract_created(
+            [0x05; 32].into(),
+            Default::default()
+        )));
+    }
+}
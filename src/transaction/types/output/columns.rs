@@ -0,0 +1,122 @@
+use super::{Output, OutputRepr};
+
+use fuel_types::{Address, AssetId, Bytes32, ContractId, Word};
+
+/// Flat row of an [`Output`], for columnar storage.
+///
+/// Every column that isn't a field of the output variant is `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OutputColumns {
+    pub repr: OutputRepr,
+    pub to: Option<Address>,
+    pub amount: Option<Word>,
+    pub asset_id: Option<AssetId>,
+    pub input_index: Option<u8>,
+    pub balance_root: Option<Bytes32>,
+    pub state_root: Option<Bytes32>,
+    pub contract_id: Option<ContractId>,
+    pub recipient: Option<Address>,
+    pub nonce: Option<Bytes32>,
+    pub digest: Option<Bytes32>,
+}
+
+impl Output {
+    /// Map the output to a flat row, with a column per field of every variant.
+    pub fn to_columns(&self) -> OutputColumns {
+        OutputColumns {
+            repr: self.repr(),
+            to: self.to().copied(),
+            amount: self.amount(),
+            asset_id: self.asset_id().copied(),
+            input_index: self.input_index(),
+            balance_root: self.balance_root().copied(),
+            state_root: self.state_root().copied(),
+            contract_id: self.contract_id().copied(),
+            recipient: self.recipient().copied(),
+            nonce: self.nonce().copied(),
+            digest: self.digest().copied(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_columns_populates_variant_fields() {
+        let address: Address = [0x01; 32].into();
+        let asset_id: AssetId = [0x02; 32].into();
+        let balance_root: Bytes32 = [0x03; 32].into();
+        let state_root: Bytes32 = [0x04; 32].into();
+        let contract_id: ContractId = [0x05; 32].into();
+
+        let empty = |repr| OutputColumns {
+            repr,
+            to: None,
+            amount: None,
+            asset_id: None,
+            input_index: None,
+            balance_root: None,
+            state_root: None,
+            contract_id: None,
+            recipient: None,
+            nonce: None,
+            digest: None,
+        };
+
+        let coin = OutputColumns {
+            to: Some(address),
+            amount: Some(10),
+            asset_id: Some(asset_id),
+            ..empty(OutputRepr::Coin)
+        };
+        assert_eq!(coin, Output::coin(address, 10, asset_id).to_columns());
+
+        assert_eq!(
+            OutputColumns {
+                repr: OutputRepr::Change,
+                ..coin.clone()
+            },
+            Output::change(address, 10, asset_id).to_columns()
+        );
+
+        assert_eq!(
+            OutputColumns {
+                repr: OutputRepr::Variable,
+                ..coin
+            },
+            Output::variable(address, 10, asset_id).to_columns()
+        );
+
+        assert_eq!(
+            OutputColumns {
+                input_index: Some(6),
+                balance_root: Some(balance_root),
+                state_root: Some(state_root),
+                ..empty(OutputRepr::Contract)
+            },
+            Output::contract(6, balance_root, state_root).to_columns()
+        );
+
+        assert_eq!(
+            OutputColumns {
+                amount: Some(10),
+                recipient: Some(address),
+                nonce: Some(Bytes32::zeroed()),
+                digest: Some(Bytes32::zeroed()),
+                ..empty(OutputRepr::Message)
+            },
+            Output::message(address, 10).to_columns()
+        );
+
+        assert_eq!(
+            OutputColumns {
+                state_root: Some(state_root),
+                contract_id: Some(contract_id),
+                ..empty(OutputRepr::ContractCreated)
+            },
+            Output::contract_created(contract_id, state_root).to_columns()
+        );
+    }
+}
@@ -71,6 +71,20 @@ impl OutputRepr {
         }
     }
 
+    pub const fn message_nonce_offset(&self) -> Option<usize> {
+        match self {
+            Self::Message => Some(OUTPUT_MESSAGE_NONCE_OFFSET),
+            _ => None,
+        }
+    }
+
+    pub const fn message_digest_offset(&self) -> Option<usize> {
+        match self {
+            Self::Message => Some(OUTPUT_MESSAGE_DIGEST_OFFSET),
+            _ => None,
+        }
+    }
+
     pub const fn from_output(output: &Output) -> Self {
         match output {
             Output::Coin { .. } => Self::Coin,
@@ -114,3 +128,74 @@ impl From<&mut Output> for OutputRepr {
         Self::from_output(o)
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    use fuel_types::bytes::{Deserializable, SizedBytes};
+
+    use std::io::Write;
+
+    #[test]
+    fn decode_discriminant_matches_output_repr() {
+        let outputs = [
+            Output::coin(Default::default(), 0, Default::default()),
+            Output::contract(0, Default::default(), Default::default()),
+            Output::message(Default::default(), 0),
+            Output::change(Default::default(), 0, Default::default()),
+            Output::variable(Default::default(), 0, Default::default()),
+            Output::contract_created(Default::default(), Default::default()),
+        ];
+
+        for (discriminant, expected) in outputs.iter().enumerate() {
+            let discriminant = discriminant as Word;
+
+            assert_eq!(discriminant, OutputRepr::from_output(expected) as Word);
+
+            let mut bytes = vec![0u8; expected.serialized_size()];
+            bytes[..8].copy_from_slice(&discriminant.to_be_bytes());
+
+            let output = Output::from_bytes(&bytes).expect("valid discriminant");
+
+            assert_eq!(expected, &output);
+        }
+    }
+
+    #[test]
+    fn message_offsets_match_serialized_fields() {
+        use fuel_types::bytes::SerializableVec;
+
+        let (recipient, nonce, digest) = ([0xaa; 32].into(), [0xbb; 32].into(), [0xcc; 32].into());
+        let mut output = Output::Message {
+            recipient,
+            amount: 10,
+            nonce,
+            digest,
+        };
+
+        let bytes = output.to_bytes();
+        let repr = output.repr();
+        let field = |offset: Option<usize>| {
+            let offset = offset.expect("message output field");
+            &bytes[offset..offset + 32]
+        };
+
+        assert_eq!(recipient.as_ref(), field(repr.recipient_offset()));
+        assert_eq!(nonce.as_ref(), field(repr.message_nonce_offset()));
+        assert_eq!(digest.as_ref(), field(repr.message_digest_offset()));
+        assert_eq!(OUTPUT_MESSAGE_SIZE, bytes.len());
+    }
+
+    #[test]
+    fn decode_rejects_unknown_discriminant() {
+        let mut bytes = vec![0u8; 128];
+        bytes[..8].copy_from_slice(&0x06u64.to_be_bytes());
+
+        let err = Output::default()
+            .write(&bytes)
+            .expect_err("unknown discriminant should fail");
+
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    }
+}
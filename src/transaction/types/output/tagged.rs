@@ -0,0 +1,68 @@
+use super::Output;
+
+use fuel_types::{Address, AssetId, Bytes32, ContractId, Word};
+
+/// [`Output`] represented with a `type` field discriminant, as the Fuel GraphQL schema does.
+///
+/// The representation of [`Output`] itself is left untouched, so non self-describing formats
+/// such as bincode keep working; wrap the outputs only where the tagged JSON is expected.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct TaggedOutput(#[serde(with = "TaggedOutputDef")] pub Output);
+
+// Internally tagged enums require a self-describing format, such as JSON
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Output", tag = "type")]
+enum TaggedOutputDef {
+    Coin {
+        to: Address,
+        amount: Word,
+        asset_id: AssetId,
+    },
+
+    Contract {
+        input_index: u8,
+        balance_root: Bytes32,
+        state_root: Bytes32,
+    },
+
+    Message {
+        recipient: Address,
+        amount: Word,
+        #[serde(default)]
+        nonce: Bytes32,
+        #[serde(default)]
+        digest: Bytes32,
+    },
+
+    Change {
+        to: Address,
+        amount: Word,
+        asset_id: AssetId,
+    },
+
+    Variable {
+        to: Address,
+        amount: Word,
+        asset_id: AssetId,
+    },
+
+    ContractCreated {
+        contract_id: ContractId,
+        state_root: Bytes32,
+    },
+}
+
+impl From<Output> for TaggedOutput {
+    fn from(output: Output) -> Self {
+        Self(output)
+    }
+}
+
+impl From<TaggedOutput> for Output {
+    fn from(output: TaggedOutput) -> Self {
+        output.0
+    }
+}
@@ -7,10 +7,12 @@ use crate::transaction::{
     metadata::CommonMetadata,
     Chargeable,
 };
+#[cfg(feature = "std")]
+use crate::Receipt;
 use crate::{CheckError, ConsensusParameters, Input, Output, Witness};
 use derivative::Derivative;
 use fuel_types::bytes::{SizedBytes, WORD_SIZE};
-use fuel_types::{bytes, Bytes32, Word};
+use fuel_types::{bytes, Address, Bytes32, Word};
 
 #[cfg(feature = "std")]
 use std::io;
@@ -79,19 +81,34 @@ impl crate::UniqueIdentifier for Script {
             return id;
         }
 
+        self.id_digest_without_witnesses()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Script {
+    /// Hash of the transaction with the witnesses, the receipts root and the other fields set
+    /// during the execution emptied.
+    ///
+    /// It is the digest of [`crate::UniqueIdentifier::id`], always computed from the current
+    /// fields instead of the cached id, so transactions differing only by their witnesses share
+    /// it.
+    pub fn id_digest_without_witnesses(&self) -> Bytes32 {
         let mut clone = self.clone();
 
         // Empties fields that should be zero during the signing.
-        *clone.receipts_root_mut() = Default::default();
-        clone.inputs_mut().iter_mut().for_each(Input::prepare_sign);
-        clone
-            .outputs_mut()
-            .iter_mut()
-            .for_each(Output::prepare_sign);
-        clone.witnesses_mut().clear();
+        use crate::transaction::sign_mask::{prepare_sign_script, SignMask};
+
+        prepare_sign_script(&mut clone, SignMask::All);
 
         fuel_crypto::Hasher::hash(clone.to_bytes().as_slice())
     }
+
+    /// Write the root of `receipts`, as computed by [`Receipt::root`], into the receipts root
+    /// of the transaction.
+    pub fn set_receipts_root_from(&mut self, receipts: &[Receipt]) {
+        self.receipts_root = Receipt::root(receipts);
+    }
 }
 
 impl Chargeable for Script {
@@ -115,16 +132,12 @@ impl Chargeable for Script {
 impl Checkable for Script {
     #[cfg(feature = "std")]
     fn check_signatures(&self) -> Result<(), CheckError> {
+        use crate::transaction::checkable::check_inputs_signatures;
         use crate::UniqueIdentifier;
 
         let id = self.id();
 
-        self.inputs()
-            .iter()
-            .enumerate()
-            .try_for_each(|(index, input)| input.check_signature(index, &id, &self.witnesses))?;
-
-        Ok(())
+        check_inputs_signatures(self.inputs(), &id, &self.witnesses)
     }
 
     fn check_without_signatures(
@@ -149,6 +162,18 @@ impl Checkable for Script {
                 Output::ContractCreated { .. } => {
                     Err(CheckError::TransactionScriptOutputContractCreated { index })
                 }
+                Output::Message {
+                    recipient,
+                    amount,
+                    nonce,
+                    digest,
+                } if recipient != &Address::zeroed()
+                    || *amount != 0
+                    || nonce != &Bytes32::zeroed()
+                    || digest != &Bytes32::zeroed() =>
+                {
+                    Err(CheckError::TransactionScriptOutputMessageNotZeroed { index })
+                }
                 _ => Ok(()),
             })?;
 
@@ -182,15 +207,16 @@ impl SizedBytes for Script {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub mod checked {
     use crate::checked_transaction::{initial_free_balances, AvailableBalances};
+    #[cfg(feature = "std")]
+    use crate::Cacheable;
     use crate::{
-        Cacheable, CheckError, Checkable, Checked, ConsensusParameters, IntoChecked, Script,
-        TransactionFee,
+        CheckError, Checkable, Checked, ConsensusParameters, IntoChecked, Script, TransactionFee,
     };
+    use alloc::collections::BTreeMap;
     use fuel_types::{AssetId, Word};
-    use std::collections::BTreeMap;
 
     #[derive(Debug, Clone, Eq, PartialEq, Hash)]
     pub struct CheckedMetadata {
@@ -205,12 +231,15 @@ pub mod checked {
     impl IntoChecked for Script {
         type Metadata = CheckedMetadata;
 
+        #[cfg_attr(not(feature = "std"), allow(unused_mut))]
         fn into_checked_basic(
             mut self,
             block_height: Word,
             params: &ConsensusParameters,
         ) -> Result<Checked<Self>, CheckError> {
+            #[cfg(feature = "std")]
             self.precompute();
+
             self.check_without_signatures(block_height, params)?;
 
             // validate fees and compute free balances
@@ -626,6 +655,9 @@ impl io::Write for Script {
         let (inputs_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
         let (outputs_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
         let (witnesses_len, buf) = unsafe { bytes::restore_usize_unchecked(buf) };
+
+        super::check_elements_len(buf, &[inputs_len, outputs_len, witnesses_len])?;
+
         let (receipts_root, buf) = unsafe { bytes::restore_array_unchecked(buf) };
 
         let receipts_root = receipts_root.into();
@@ -8,6 +8,8 @@ use rand::{
 
 use core::cmp::Ordering;
 
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::io;
 
@@ -34,6 +36,41 @@ impl StorageSlot {
     }
 }
 
+#[cfg(feature = "std")]
+impl StorageSlot {
+    /// Decode `count` consecutive storage slots from `buf`.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] unless the keys are strictly ascending, so
+    /// duplicated or unordered slots are rejected without a full transaction validation.
+    pub fn decode_slots_checked(buf: &[u8], count: usize) -> io::Result<Vec<StorageSlot>> {
+        let len = count.checked_mul(Self::SLOT_SIZE).ok_or_else(bytes::eof)?;
+
+        if buf.len() < len {
+            return Err(bytes::eof());
+        }
+
+        let slots = buf[..len]
+            .chunks_exact(Self::SLOT_SIZE)
+            .map(|slot| {
+                // Safety: chunks are exactly `SLOT_SIZE` long
+                let (key, slot) = unsafe { bytes::restore_array_unchecked(slot) };
+                let (value, _) = unsafe { bytes::restore_array_unchecked(slot) };
+
+                Self::new(key.into(), value.into())
+            })
+            .collect::<Vec<_>>();
+
+        if !slots.windows(2).all(|s| s[0].key < s[1].key) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The storage slots keys must be strictly ascending!",
+            ));
+        }
+
+        Ok(slots)
+    }
+}
+
 impl From<&StorageSlot> for Bytes64 {
     fn from(s: &StorageSlot) -> Self {
         let mut buf = [0u8; StorageSlot::SLOT_SIZE];
@@ -108,7 +145,7 @@ impl bytes::SizedBytes for StorageSlot {
 
 impl PartialOrd for StorageSlot {
     fn partial_cmp(&self, other: &StorageSlot) -> Option<Ordering> {
-        Some(self.key.cmp(&other.key))
+        Some(self.cmp(other))
     }
 }
 
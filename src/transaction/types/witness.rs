@@ -1,4 +1,6 @@
+use fuel_crypto::{Hasher, Signature};
 use fuel_types::bytes::{self, WORD_SIZE};
+use fuel_types::Bytes32;
 
 #[cfg(feature = "random")]
 use rand::{
@@ -11,6 +13,10 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::io;
 
+/// Witness data of a transaction.
+///
+/// Equality and hashing are defined by the witness bytes only; the capacity of the
+/// underlying allocation is never taken into account.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Witness {
@@ -29,6 +35,48 @@ impl Witness {
     pub fn into_inner(self) -> Vec<u8> {
         self.data
     }
+
+    /// Shrink the capacity of the witness bytes to their length.
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    /// Hash of the witness bytes, suitable for fast set membership checks.
+    pub fn digest(&self) -> Bytes32 {
+        Hasher::hash(self.data.as_slice())
+    }
+
+    /// Witness holding a single signature, as expected by signed inputs.
+    pub fn from_signature(signature: &Signature) -> Self {
+        signature.as_ref().into()
+    }
+
+    /// View the witness as a signature, or `None` if it isn't [`Signature::LEN`] bytes long.
+    ///
+    /// Only the length is checked; whether the bytes are a valid signature is only known once
+    /// a public key is recovered from it.
+    pub fn as_signature(&self) -> Option<&Signature> {
+        (self.data.len() == Signature::LEN)
+            // Safety: checked length
+            .then(|| unsafe { Signature::as_ref_unchecked(self.data.as_slice()) })
+    }
+
+    /// Concatenate `signatures` into a single witness, e.g. for predicates checking several
+    /// signatures.
+    pub fn from_signatures(signatures: &[Signature]) -> Self {
+        signatures
+            .iter()
+            .flat_map(|signature| signature.iter().copied())
+            .collect::<Vec<u8>>()
+            .into()
+    }
+
+    /// Split the witness into [`Signature::LEN`] bytes chunks.
+    ///
+    /// Trailing bytes that don't fill a whole signature are ignored.
+    pub fn signatures(&self) -> impl Iterator<Item = &[u8]> {
+        self.data.chunks_exact(Signature::LEN)
+    }
 }
 
 impl From<Vec<u8>> for Witness {
@@ -99,3 +147,85 @@ impl io::Write for Witness {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equality_ignores_capacity() {
+        let bytes = [0xfau8; 40];
+
+        let mut data = Vec::with_capacity(1024);
+        data.extend_from_slice(&bytes);
+
+        let a = Witness::from(data);
+        let b = Witness::from(&bytes[..]);
+
+        assert_ne!(a.as_vec().capacity(), b.as_vec().capacity());
+        assert_eq!(a, b);
+        assert_eq!(a.digest(), b.digest());
+
+        let c = Witness::from(&bytes[1..]);
+        assert_ne!(a, c);
+        assert_ne!(a.digest(), c.digest());
+    }
+
+    #[test]
+    fn signatures_round_trip() {
+        use fuel_crypto::{Message, SecretKey};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let message = Message::new(b"multisig");
+
+        let signatures = (0..3)
+            .map(|_| Signature::sign(&SecretKey::random(rng), &message))
+            .collect::<Vec<_>>();
+
+        let witness = Witness::from_signatures(&signatures);
+        assert_eq!(3 * Signature::LEN, witness.as_ref().len());
+
+        let signatures_p = witness
+            .signatures()
+            .map(|bytes| {
+                fuel_types::Bytes64::try_from(bytes)
+                    .map(Signature::from)
+                    .expect("signature has 64 bytes")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(signatures, signatures_p);
+
+        let mut witness = witness;
+        witness.extend([0xfa; 3]);
+        assert_eq!(3, witness.signatures().count());
+    }
+
+    #[test]
+    fn signature_round_trip() {
+        use fuel_crypto::{Message, SecretKey};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let signature = Signature::sign(&SecretKey::random(rng), &Message::new(b"witness"));
+
+        let witness = Witness::from_signature(&signature);
+        assert_eq!(Some(&signature), witness.as_signature());
+
+        // The bytes aren't checked to be a valid signature
+        let witness = Witness::from(vec![0xfa; Signature::LEN]);
+        assert!(witness.as_signature().is_some());
+
+        assert_eq!(
+            None,
+            Witness::from(vec![0xfa; Signature::LEN - 1]).as_signature()
+        );
+        assert_eq!(
+            None,
+            Witness::from(vec![0xfa; Signature::LEN + 1]).as_signature()
+        );
+        assert_eq!(None, Witness::default().as_signature());
+    }
+}
@@ -73,6 +73,8 @@ mod use_std {
             // When and if a new variant is added, this implementation enforces it will be
             // listed here.
             debug_assert!({
+                // `Input` and `Output` are non-exhaustive, so the wildcard arms are required
+                // here; the exhaustive match lives in the `variants_are_listed` test of fuel-tx.
                 Input::from_bytes(&[])
                     .map(|i| match i {
                         Input::CoinSigned { .. } => (),
@@ -80,6 +82,7 @@ mod use_std {
                         Input::Contract { .. } => (),
                         Input::MessageSigned { .. } => (),
                         Input::MessagePredicate { .. } => (),
+                        _ => unreachable!("unlisted input variant"),
                     })
                     .unwrap_or(());
 
@@ -91,6 +94,7 @@ mod use_std {
                         Output::Change { .. } => (),
                         Output::Variable { .. } => (),
                         Output::ContractCreated { .. } => (),
+                        _ => unreachable!("unlisted output variant"),
                     })
                     .unwrap_or(());
 
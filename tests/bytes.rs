@@ -1,6 +1,6 @@
 use fuel_asm::Opcode;
 use fuel_tx::*;
-use fuel_tx_test_helpers::{generate_bytes, generate_nonempty_padded_bytes};
+use fuel_tx_test_helpers::{generate_bytes, generate_nonempty_padded_bytes, TransactionFactory};
 use fuel_types::{bytes, Immediate24};
 use rand::rngs::StdRng;
 use rand::{Rng, RngCore, SeedableRng};
@@ -27,8 +27,9 @@ where
     for data in data.iter() {
         let d_s = bincode::serialize(&data).expect("Failed to serialize data");
         // Safety: bincode/serde fails to understand the elision so this is a cheap way to convince it
-        let d_s: T = bincode::deserialize(unsafe { std::mem::transmute(d_s.as_slice()) })
-            .expect("Failed to deserialize data");
+        let d_s: T =
+            bincode::deserialize(unsafe { std::mem::transmute::<&[u8], &[u8]>(d_s.as_slice()) })
+                .expect("Failed to deserialize data");
 
         assert_eq!(&d_s, data);
 
@@ -86,6 +87,60 @@ fn witness() {
     assert_encoding_correct(&[w, Witness::default()]);
 }
 
+#[test]
+fn storage_slot() {
+    use bytes::SerializableVec;
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let slots: Vec<StorageSlot> = (0..10).map(|_| rng.gen()).collect();
+
+    assert_encoding_correct(&slots);
+    assert_encoding_correct(&[StorageSlot::default()]);
+
+    for slot in slots {
+        let b = Bytes64::from(&slot);
+
+        let b_s = bincode::serialize(&b).expect("Failed to serialize Bytes64");
+        let b_p: Bytes64 = bincode::deserialize(&b_s).expect("Failed to deserialize Bytes64");
+
+        assert_eq!(b, b_p);
+        assert_eq!(slot, StorageSlot::from(&b_p));
+        assert_eq!(slot.clone().to_bytes().as_slice(), b.as_ref());
+    }
+}
+
+#[test]
+fn storage_slot_decode_checked() {
+    use bytes::SerializableVec;
+
+    let slot = |key: u8| StorageSlot::new([key; 32].into(), [0xfa; 32].into());
+    let encode = |slots: &[StorageSlot]| {
+        slots
+            .iter()
+            .flat_map(|slot| slot.clone().to_bytes())
+            .collect::<Vec<u8>>()
+    };
+
+    let slots = vec![slot(1), slot(2), slot(3)];
+    let decoded = StorageSlot::decode_slots_checked(&encode(&slots), slots.len())
+        .expect("Failed to decode ordered slots");
+    assert_eq!(slots, decoded);
+
+    let duplicated = [slot(1), slot(2), slot(2)];
+    let err = StorageSlot::decode_slots_checked(&encode(&duplicated), duplicated.len())
+        .expect_err("Duplicated keys should be rejected");
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+    let unordered = [slot(2), slot(1)];
+    let err = StorageSlot::decode_slots_checked(&encode(&unordered), unordered.len())
+        .expect_err("Unordered keys should be rejected");
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+    let err = StorageSlot::decode_slots_checked(&encode(&slots), slots.len() + 1)
+        .expect_err("Missing slots should be rejected");
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+}
+
 #[test]
 fn input() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -147,6 +202,97 @@ fn output() {
     ]);
 }
 
+#[test]
+fn input_output_match_with_wildcard() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // `Input` and `Output` are non-exhaustive, so external matches need a wildcard arm
+    let input = Input::contract(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen());
+    let contract_id = match input {
+        Input::Contract { contract_id, .. } => Some(contract_id),
+        _ => None,
+    };
+    assert_eq!(input.contract_id(), contract_id.as_ref());
+    assert_eq!(InputRepr::Contract, input.repr());
+
+    let output = Output::coin(rng.gen(), rng.next_u64(), rng.gen());
+    let amount = match output {
+        Output::Coin { amount, .. } => Some(amount),
+        _ => None,
+    };
+    assert_eq!(output.amount(), amount);
+    assert_eq!(OutputRepr::Coin, output.repr());
+}
+
+#[test]
+fn input_signed_predicate_boundary() {
+    use fuel_types::bytes::{Deserializable, SerializableVec};
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    fn round_trip(mut input: Input) -> Input {
+        let decoded = Input::from_bytes(&input.to_bytes()).expect("failed to decode input");
+        assert_eq!(input, decoded);
+        decoded
+    }
+
+    // Signed coins are encoded with an empty predicate and must not decode as predicates
+    let input = round_trip(Input::coin_signed(
+        rng.gen(),
+        rng.gen(),
+        rng.next_u64(),
+        rng.gen(),
+        rng.gen(),
+        3,
+        rng.next_u64(),
+    ));
+    assert!(input.is_coin_signed());
+    assert_eq!(Some(3), input.witness_index());
+    assert_eq!(None, input.input_predicate());
+
+    // The variant is decided by the predicate alone, so empty predicate data is still a predicate
+    let predicate = generate_nonempty_padded_bytes(rng);
+    let input = round_trip(Input::coin_predicate(
+        rng.gen(),
+        rng.gen(),
+        rng.next_u64(),
+        rng.gen(),
+        rng.gen(),
+        rng.next_u64(),
+        predicate.clone(),
+        vec![],
+    ));
+    assert!(input.is_coin_predicate());
+    assert_eq!(None, input.witness_index());
+    assert_eq!(Some(predicate.as_slice()), input.input_predicate());
+    assert_eq!(Some(&[][..]), input.input_predicate_data());
+
+    let input = round_trip(Input::message_signed(
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        3,
+        generate_bytes(rng),
+    ));
+    assert!(input.is_message_signed());
+    assert_eq!(Some(3), input.witness_index());
+
+    let input = round_trip(Input::message_predicate(
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        generate_bytes(rng),
+        predicate,
+        vec![],
+    ));
+    assert!(input.is_message_predicate());
+    assert_eq!(None, input.witness_index());
+}
+
 #[test]
 fn receipt() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -660,6 +806,231 @@ fn transaction() {
     ]);
 }
 
+#[test]
+fn transaction_encode_to_writer() {
+    use fuel_types::bytes::SerializableVec;
+
+    fn assert_encoded_eq(tx: Transaction) {
+        let mut sink = Vec::new();
+        let n = tx
+            .encode_to_writer(&mut sink)
+            .expect("failed to encode transaction");
+
+        assert_eq!(n, sink.len());
+        assert_eq!(tx.clone().to_bytes(), sink);
+    }
+
+    TransactionFactory::<_, fuel_tx::Script>::from_seed(3493)
+        .take(20)
+        .for_each(|(tx, _)| assert_encoded_eq(tx.into()));
+
+    TransactionFactory::<_, Create>::from_seed(3493)
+        .take(20)
+        .for_each(|(tx, _)| assert_encoded_eq(tx.into()));
+
+    TransactionFactory::<_, Mint>::from_seed(3493)
+        .take(20)
+        .for_each(|tx| assert_encoded_eq(tx.into()));
+}
+
+#[test]
+fn transaction_encoding_time_is_linear() {
+    use fuel_crypto::SecretKey;
+    use fuel_tx::field::Outputs;
+    use fuel_tx::Cacheable;
+    use fuel_types::bytes::{Deserializable, SerializableVec};
+    use std::time::{Duration, Instant};
+
+    const SMALL: usize = 30;
+    const LARGE: usize = 8 * SMALL;
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+
+    // Fastest of a few rounds, so a busy machine doesn't skew the ratio
+    let mut encoding_time = |n: usize| {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        for _ in 0..n {
+            builder
+                .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0)
+                .add_output(Output::coin(rng.gen(), rng.gen(), rng.gen()));
+        }
+        let tx = builder.finalize();
+
+        (0..10)
+            .map(|_| {
+                let start = Instant::now();
+
+                let bytes = tx.clone().to_bytes();
+                let mut tx_p =
+                    fuel_tx::Script::from_bytes(&bytes).expect("failed to decode script");
+                tx_p.precompute();
+                for i in 0..n {
+                    assert!(tx_p.inputs_offset_at(i).is_some());
+                    assert!(tx_p.outputs_offset_at(i).is_some());
+                }
+
+                let elapsed = start.elapsed();
+                assert_eq!(tx, tx_p);
+
+                elapsed
+            })
+            .min()
+            .unwrap_or(Duration::ZERO)
+    };
+
+    let small = encoding_time(SMALL);
+    let large = encoding_time(LARGE);
+
+    // Linear encoding takes about 8 times longer, quadratic about 64 times. The offsets are
+    // looked up once precomputed, as the validation does
+    let ratio = large.as_secs_f64() / small.as_secs_f64().max(f64::EPSILON);
+    assert!(
+        ratio < 24.0,
+        "encoding {} items took {:?}, {} items took {:?}",
+        SMALL,
+        small,
+        LARGE,
+        large
+    );
+}
+
+#[test]
+fn transaction_decode_and_precompute() {
+    use fuel_types::bytes::SerializableVec;
+
+    fn assert_precomputed(tx: Transaction) {
+        let tx_p = Transaction::decode_and_precompute(&tx.clone().to_bytes())
+            .expect("failed to decode transaction");
+
+        assert!(tx_p.is_computed());
+        assert_eq!(tx, tx_p);
+        assert_eq!(tx.id(), tx_p.id());
+    }
+
+    TransactionFactory::<_, fuel_tx::Script>::from_seed(3493)
+        .take(20)
+        .for_each(|(tx, _)| assert_precomputed(tx.into()));
+
+    TransactionFactory::<_, Create>::from_seed(3493)
+        .take(20)
+        .for_each(|(tx, _)| assert_precomputed(tx.into()));
+
+    TransactionFactory::<_, Mint>::from_seed(3493)
+        .take(20)
+        .for_each(|tx| assert_precomputed(tx.into()));
+}
+
+#[test]
+fn transaction_decode_trailing_bytes() {
+    use fuel_types::bytes::SerializableVec;
+
+    fn assert_decode(mut tx: Transaction) {
+        let mut encoded = tx.to_bytes();
+        assert_eq!(
+            tx,
+            Transaction::decode(&encoded, false).expect("failed to decode")
+        );
+        assert_eq!(
+            tx,
+            Transaction::decode(&encoded, true).expect("failed to decode")
+        );
+
+        encoded.extend_from_slice(&[0xfa; bytes::WORD_SIZE]);
+        let err = Transaction::decode(&encoded, false).expect_err("expected trailing bytes");
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!(
+            tx,
+            Transaction::decode(&encoded, true).expect("failed to decode")
+        );
+    }
+
+    TransactionFactory::<_, fuel_tx::Script>::from_seed(3493)
+        .take(10)
+        .for_each(|(tx, _)| assert_decode(tx.into()));
+
+    TransactionFactory::<_, Create>::from_seed(3493)
+        .take(10)
+        .for_each(|(tx, _)| assert_decode(tx.into()));
+
+    TransactionFactory::<_, Mint>::from_seed(3493)
+        .take(10)
+        .for_each(|tx| assert_decode(tx.into()));
+}
+
+#[test]
+fn transaction_repr_from_bytes() {
+    use fuel_types::bytes::SerializableVec;
+
+    TransactionFactory::<_, fuel_tx::Script>::from_seed(3493)
+        .take(20)
+        .for_each(|(mut tx, _)| {
+            let repr = TransactionRepr::from_bytes(&tx.to_bytes()).expect("failed to read repr");
+            assert_eq!(TransactionRepr::Script, repr);
+        });
+
+    TransactionFactory::<_, Create>::from_seed(3493)
+        .take(20)
+        .for_each(|(mut tx, _)| {
+            let repr = TransactionRepr::from_bytes(&tx.to_bytes()).expect("failed to read repr");
+            assert_eq!(TransactionRepr::Create, repr);
+        });
+
+    let err = TransactionRepr::from_bytes(&[0u8; 4]).expect_err("expected short buffer");
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+    let err = TransactionRepr::from_bytes(&Word::MAX.to_be_bytes())
+        .expect_err("expected invalid identifier");
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+}
+
+#[test]
+fn transaction_decode_header() {
+    use fuel_tx::field::{GasLimit, GasPrice, Maturity, Outputs, Witnesses};
+    use fuel_types::bytes::SerializableVec;
+
+    fn assert_header_eq<Tx>(mut tx: Tx)
+    where
+        Tx: GasPrice + GasLimit + Maturity + Inputs + Outputs + Witnesses + SerializableVec,
+        Transaction: From<Tx>,
+    {
+        let header = Transaction::decode_header(&tx.to_bytes()).expect("failed to decode header");
+
+        assert_eq!(*tx.gas_price(), header.gas_price);
+        assert_eq!(*tx.gas_limit(), header.gas_limit);
+        assert_eq!(*tx.maturity(), header.maturity);
+        assert_eq!(tx.inputs().len(), header.inputs_len);
+        assert_eq!(tx.outputs().len(), header.outputs_len);
+        assert_eq!(tx.witnesses().len(), header.witnesses_len);
+        assert_eq!(TransactionRepr::from(&tx.into()), header.kind);
+    }
+
+    TransactionFactory::<_, fuel_tx::Script>::from_seed(3493)
+        .take(20)
+        .for_each(|(tx, _)| assert_header_eq(tx));
+
+    TransactionFactory::<_, Create>::from_seed(3493)
+        .take(20)
+        .for_each(|(tx, _)| assert_header_eq(tx));
+
+    TransactionFactory::<_, Mint>::from_seed(3493)
+        .take(20)
+        .for_each(|mut tx| {
+            let header =
+                Transaction::decode_header(&tx.to_bytes()).expect("failed to decode header");
+
+            assert_eq!(TransactionRepr::Mint, header.kind);
+            assert_eq!(tx.outputs().len(), header.outputs_len);
+            assert_eq!(0, header.inputs_len);
+        });
+
+    let mut tx = Transaction::default();
+    let bytes = tx.to_bytes();
+    let err = Transaction::decode_header(&bytes[..bytes.len() / 2])
+        .expect_err("truncated header should fail");
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+}
+
 #[test]
 fn create_input_data_offset() {
     let rng = &mut StdRng::seed_from_u64(8586);
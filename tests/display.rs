@@ -31,3 +31,187 @@ fn to_from_str() {
             assert_eq!(tx, tx_p);
         });
 }
+
+#[test]
+fn to_json_canonical_sorts_keys() {
+    TransactionFactory::<_, Script>::from_seed(1295)
+        .take(20)
+        .for_each(|(tx, _)| {
+            let tx: Transaction = tx.into();
+            let json = tx.to_json_canonical();
+
+            assert_eq!(json, tx.clone().to_json_canonical());
+            assert_eq!(Some(&tx), Transaction::from_json(&json).as_ref());
+
+            // Declared as `gas_price`, `gas_limit`, `maturity`
+            let gas_limit = json.find(r#""gas_limit""#).expect("missing gas limit");
+            let gas_price = json.find(r#""gas_price""#).expect("missing gas price");
+            let maturity = json.find(r#""maturity""#).expect("missing maturity");
+
+            assert!(gas_limit < gas_price);
+            assert!(gas_price < maturity);
+        });
+
+    TransactionFactory::<_, Create>::from_seed(1295)
+        .take(20)
+        .for_each(|(tx, _)| {
+            let tx: Transaction = tx.into();
+            let json = tx.to_json_canonical();
+
+            assert_eq!(Some(tx), Transaction::from_json(json));
+        });
+}
+
+#[test]
+fn receipt_abi_json() {
+    let json = r#"{
+        "type": "Call",
+        "from": "0x0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+        "to": "0x0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+        "amount": "0x0000000000000064",
+        "asset_id": "0x0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+        "gas": "0x00000000000003e8",
+        "param1": "0x0000000000000001",
+        "param2": "0x0000000000000002",
+        "pc": "0x0000000000000010",
+        "is": "0x0000000000000008"
+    }"#;
+
+    let receipt = Receipt::from_abi_json(json).expect("failed to restore call receipt");
+    let expected = Receipt::call(
+        [0x0a; 32].into(),
+        [0x0b; 32].into(),
+        100,
+        [0x0c; 32].into(),
+        1000,
+        1,
+        2,
+        16,
+        8,
+    );
+    assert_eq!(expected, receipt);
+
+    let abi: serde_json::Value = serde_json::from_str(&receipt.to_abi_json()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(json, abi);
+
+    let log_data = Receipt::log_data(
+        [0x0a; 32].into(),
+        1,
+        2,
+        3,
+        [0x0d; 32].into(),
+        vec![0xfa; 4],
+        5,
+        6,
+    );
+    let abi: serde_json::Value = serde_json::from_str(&log_data.to_abi_json()).unwrap();
+    let json = serde_json::json!({
+        "type": "LogData",
+        "id": "0x0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+        "ra": "0x0000000000000001",
+        "rb": "0x0000000000000002",
+        "ptr": "0x0000000000000003",
+        "len": "0x0000000000000008",
+        "digest": "0x0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+        "data": "0xfafafafa",
+        "pc": "0x0000000000000005",
+        "is": "0x0000000000000006"
+    });
+    assert_eq!(json, abi);
+    assert_eq!(
+        Some(log_data.clone()),
+        Receipt::from_abi_json(log_data.to_abi_json())
+    );
+
+    let reason = InstructionResult::error(
+        PanicReason::MemoryOverflow,
+        fuel_asm::Instruction::from(0x1234_5600),
+    );
+    let panic = Receipt::panic([0x0a; 32].into(), reason, 5, 6);
+    let abi: serde_json::Value = serde_json::from_str(&panic.to_abi_json()).unwrap();
+    assert_eq!("Panic", abi["type"]);
+    assert_eq!(format!("0x{:016x}", Word::from(reason)), abi["reason"]);
+    assert_eq!(
+        Some(panic.clone()),
+        Receipt::from_abi_json(panic.to_abi_json())
+    );
+
+    // Hex strings are only decoded where the ABI expects them
+    let mut abi = abi;
+    abi["reason"] = "0x0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a".into();
+    assert!(Receipt::from_abi_json(abi.to_string()).is_none());
+
+    // Non ASCII hex strings are rejected instead of sliced
+    let revert = r#"{"type":"Revert","id":"0xaé0","ra":"0x0000000000000000","pc":"0x0000000000000000","is":"0x0000000000000000"}"#;
+    assert!(Receipt::from_abi_json(revert).is_none());
+}
+
+#[test]
+fn input_output_from_str() {
+    let input: Input = r#"{
+        "CoinSigned": {
+            "utxo_id": {
+                "tx_id": "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+                "output_index": 1
+            },
+            "owner": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "amount": 100,
+            "asset_id": "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "tx_pointer": { "block_height": 2, "tx_index": 3 },
+            "witness_index": 4,
+            "maturity": 5
+        }
+    }"#
+    .parse()
+    .expect("failed to parse input");
+
+    let expected = Input::coin_signed(
+        UtxoId::new([0x0a; 32].into(), 1),
+        [0x0b; 32].into(),
+        100,
+        [0x0c; 32].into(),
+        TxPointer::new(2, 3),
+        4,
+        5,
+    );
+    assert_eq!(expected, input);
+
+    let json = r#"{
+        "Coin": {
+            "to": "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "amount": 10,
+            "asset_id": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b"
+        }
+    }"#;
+
+    let output: Output = json.parse().expect("failed to parse output");
+    assert_eq!(
+        Output::coin([0x0a; 32].into(), 10, [0x0b; 32].into()),
+        output
+    );
+
+    assert!("{}".parse::<Output>().is_err());
+}
+
+#[test]
+fn output_json_type_tag() {
+    let output = Output::coin(Default::default(), 10, Default::default());
+
+    let json = serde_json::to_value(TaggedOutput(output)).expect("failed to serialize output");
+    assert_eq!("Coin", json["type"]);
+    assert_eq!(10, json["amount"]);
+
+    let json = json.to_string();
+    let output_p: TaggedOutput = serde_json::from_str(&json).expect("failed to restore output");
+    assert_eq!(output, output_p.into());
+
+    let json =
+        serde_json::to_string(&TaggedOutput(Output::message(Default::default(), 1))).unwrap();
+    assert!(json.starts_with(r#"{"type":"Message""#));
+
+    // The untagged representation is left untouched
+    let json = serde_json::to_value(output).expect("failed to serialize output");
+    assert!(json.get("type").is_none());
+    assert_eq!(10, json["Coin"]["amount"]);
+}
@@ -0,0 +1,101 @@
+use fuel_tx::*;
+use fuel_tx_test_helpers::TransactionFactory;
+use fuel_types::bytes::SerializableVec;
+use quickcheck_macros::quickcheck;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use std::sync::OnceLock;
+
+/// Serialized transactions of the test factory, used as seeds for the mutations.
+///
+/// Built once and shared by every quickcheck iteration.
+fn corpus() -> &'static [Vec<u8>] {
+    static CORPUS: OnceLock<Vec<Vec<u8>>> = OnceLock::new();
+
+    CORPUS.get_or_init(|| {
+        let scripts = TransactionFactory::<_, Script>::from_seed(8586)
+            .take(10)
+            .map(|(tx, _)| Transaction::from(tx));
+
+        let creates = TransactionFactory::<_, Create>::from_seed(8586)
+            .take(10)
+            .map(|(tx, _)| Transaction::from(tx));
+
+        let mints = TransactionFactory::<_, Mint>::from_seed(8586)
+            .take(10)
+            .map(Transaction::from);
+
+        scripts
+            .chain(creates)
+            .chain(mints)
+            .map(|mut tx| tx.to_bytes())
+            .collect()
+    })
+}
+
+/// Decode arbitrary bytes as a transaction.
+///
+/// The decoder must only ever return `Ok` or `Err`; a panic or an abort on allocation fails
+/// the harness. A successful decode can't consume more bytes than provided.
+fn decode(bytes: &[u8]) {
+    if let Ok((n, _)) = Transaction::try_from_bytes(bytes) {
+        assert!(n <= bytes.len());
+    }
+
+    let lenient = Transaction::decode(bytes, true);
+    let strict = Transaction::decode(bytes, false);
+
+    assert!(lenient.is_ok() || strict.is_err());
+}
+
+#[quickcheck]
+fn decode_arbitrary_bytes(bytes: Vec<u8>) {
+    decode(&bytes);
+}
+
+#[quickcheck]
+fn decode_mutated_corpus(seed: u64) {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let corpus = corpus();
+
+    let mut bytes = corpus[rng.gen_range(0..corpus.len())].clone();
+    for _ in 0..rng.gen_range(1..8) {
+        let index = rng.gen_range(0..bytes.len());
+        bytes[index] = rng.gen();
+    }
+
+    decode(&bytes);
+}
+
+#[test]
+fn decode_corpus() {
+    for bytes in corpus() {
+        let tx = Transaction::decode(bytes, false).expect("failed to decode corpus tx");
+        assert_eq!(bytes, &tx.clone().to_bytes());
+
+        // Every truncation of a valid transaction must be rejected gracefully
+        (0..bytes.len()).for_each(|len| decode(&bytes[..len]));
+    }
+}
+
+#[test]
+fn decode_rejects_counts_larger_than_buffer() {
+    use fuel_types::bytes::{Deserializable, WORD_SIZE};
+    use std::io::ErrorKind;
+
+    // The inputs count follows the identifier, the gas price and limit, the maturity and the
+    // lengths of the script and its data
+    const INPUTS_LEN_OFFSET: usize = 6 * WORD_SIZE;
+
+    let mut bytes = corpus()[0].clone();
+    let remaining = bytes.len() - INPUTS_LEN_OFFSET - 3 * WORD_SIZE;
+
+    // Each input takes at least a word, so this many inputs can't fit in the buffer
+    let count = remaining / WORD_SIZE + 1;
+    bytes[INPUTS_LEN_OFFSET..INPUTS_LEN_OFFSET + WORD_SIZE]
+        .copy_from_slice(&(count as Word).to_be_bytes());
+
+    let err = Script::from_bytes(&bytes).expect_err("Expected erroneous transaction");
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+}
@@ -1,10 +1,10 @@
 use fuel_tx::field::{
-    Inputs, Outputs, ReceiptsRoot, Salt as SaltField, StorageSlots, TxPointer as TxPointerField,
-    Witnesses,
+    GasPrice, Inputs, Outputs, ReceiptsRoot, Salt as SaltField, StorageSlots,
+    TxPointer as TxPointerField, Witnesses,
 };
 use fuel_tx::*;
 use fuel_tx_test_helpers::TransactionFactory;
-use fuel_types::bytes::{Deserializable, SerializableVec};
+use fuel_types::bytes::{Deserializable, SerializableVec, WORD_SIZE};
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 
@@ -305,6 +305,21 @@ fn outputs_assert<Tx: Outputs>(tx: &Tx, bytes: &[u8], cases: &mut TestedFields)
             let recipient_p = unsafe { Address::as_ref_unchecked(&bytes[ofs..ofs + Address::LEN]) };
 
             assert_eq!(recipient, recipient_p);
+
+            let nonce = o.nonce().expect("message output have nonce");
+            let ofs = output_ofs + o.repr().message_nonce_offset().expect("output have nonce");
+            let nonce_p = unsafe { Bytes32::as_ref_unchecked(&bytes[ofs..ofs + Bytes32::LEN]) };
+
+            assert_eq!(nonce, nonce_p);
+
+            let digest = o.digest().expect("message output have digest");
+            let ofs = output_ofs
+                + o.repr()
+                    .message_digest_offset()
+                    .expect("output have digest");
+            let digest_p = unsafe { Bytes32::as_ref_unchecked(&bytes[ofs..ofs + Bytes32::LEN]) };
+
+            assert_eq!(digest, digest_p);
         }
     });
 }
@@ -439,6 +454,29 @@ fn tx_offset_mint() {
 
             assert_eq!(*tx.tx_pointer(), tx_pointer_p);
 
+            let ofs = Transaction::from(tx.clone())
+                .mint_outputs_offset()
+                .expect("Mint should have an outputs offset");
+            assert_eq!(tx.outputs_offset(), ofs);
+
+            let tx_p = Transaction::from(tx.clone());
+            assert_eq!(None, tx_p.input_offset(0));
+            assert_eq!(None, tx_p.witness_offset(0));
+            (0..=tx.outputs().len())
+                .for_each(|i| assert_eq!(tx.outputs_offset_at(i), tx_p.output_offset(i)));
+
+            let outputs_len = bytes[ofs - WORD_SIZE..ofs]
+                .try_into()
+                .map(Word::from_be_bytes)
+                .expect("Should decode outputs length");
+            assert_eq!(tx.outputs().len() as Word, outputs_len);
+
+            if let Some(output) = tx.outputs().first() {
+                let output_p =
+                    Output::from_bytes(&bytes[ofs..]).expect("Should decode first output");
+                assert_eq!(output, &output_p);
+            }
+
             outputs_assert(&tx, &bytes, &mut cases);
         });
 
@@ -452,6 +490,152 @@ fn tx_offset_mint() {
     assert!(cases.output_recipient);
 }
 
+#[test]
+fn gas_price_splice() {
+    fn assert_splice(tx: Transaction, gas_price: Word) {
+        let ofs = tx
+            .gas_price_byte_offset()
+            .expect("Should have a gas price offset");
+
+        let mut bytes = tx.clone().to_bytes();
+        bytes[ofs..ofs + WORD_SIZE].copy_from_slice(&gas_price.to_be_bytes());
+
+        let tx_p = Transaction::from_bytes(&bytes).expect("Should decode spliced transaction");
+        let expected: Transaction = match tx {
+            Transaction::Script(mut script) => {
+                *script.gas_price_mut() = gas_price;
+                script.into()
+            }
+            Transaction::Create(mut create) => {
+                *create.gas_price_mut() = gas_price;
+                create.into()
+            }
+            Transaction::Mint(_) => unreachable!(),
+        };
+
+        assert_eq!(expected, tx_p);
+    }
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    TransactionFactory::<_, Script>::from_seed(1295)
+        .take(10)
+        .for_each(|(tx, _)| assert_splice(tx.into(), rng.gen()));
+
+    TransactionFactory::<_, Create>::from_seed(1295)
+        .take(10)
+        .for_each(|(tx, _)| assert_splice(tx.into(), rng.gen()));
+
+    TransactionFactory::<_, Mint>::from_seed(1295)
+        .take(10)
+        .for_each(|tx| assert_eq!(None, Transaction::from(tx).gas_price_byte_offset()));
+}
+
+#[test]
+fn fixed_sizes_match_minimal_serialized_size() {
+    use fuel_tx::consts::*;
+    use fuel_types::bytes::SizedBytes;
+
+    let script = Transaction::script(0, 0, 0, vec![], vec![], vec![], vec![], vec![]);
+    assert_eq!(TRANSACTION_SCRIPT_FIXED_SIZE, script.serialized_size());
+    assert_eq!(
+        TRANSACTION_SCRIPT_FIXED_SIZE,
+        script.clone().to_bytes().len()
+    );
+
+    let create = Transaction::create(
+        0,
+        0,
+        0,
+        0,
+        Default::default(),
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    );
+    assert_eq!(TRANSACTION_CREATE_FIXED_SIZE, create.serialized_size());
+    assert_eq!(
+        TRANSACTION_CREATE_FIXED_SIZE,
+        create.clone().to_bytes().len()
+    );
+
+    let mint = Transaction::mint(Default::default(), vec![]);
+    assert_eq!(TRANSACTION_MINT_FIXED_SIZE, mint.serialized_size());
+    assert_eq!(TRANSACTION_MINT_FIXED_SIZE, mint.clone().to_bytes().len());
+
+    let inputs = [
+        (
+            INPUT_COIN_FIXED_SIZE,
+            Input::coin_signed(
+                Default::default(),
+                Default::default(),
+                0,
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+            ),
+        ),
+        (
+            INPUT_CONTRACT_SIZE,
+            Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+        ),
+        (
+            INPUT_MESSAGE_FIXED_SIZE,
+            Input::message_signed(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                0,
+                0,
+                0,
+                vec![],
+            ),
+        ),
+    ];
+
+    for (size, mut input) in inputs {
+        assert_eq!(size, input.serialized_size());
+        assert_eq!(size, input.to_bytes().len());
+    }
+
+    let outputs = [
+        (
+            OUTPUT_CCV_SIZE,
+            Output::coin(Default::default(), 0, Default::default()),
+        ),
+        (
+            OUTPUT_CCV_SIZE,
+            Output::change(Default::default(), 0, Default::default()),
+        ),
+        (
+            OUTPUT_CCV_SIZE,
+            Output::variable(Default::default(), 0, Default::default()),
+        ),
+        (
+            OUTPUT_CONTRACT_SIZE,
+            Output::contract(0, Default::default(), Default::default()),
+        ),
+        (
+            OUTPUT_CONTRACT_CREATED_SIZE,
+            Output::contract_created(Default::default(), Default::default()),
+        ),
+        (OUTPUT_MESSAGE_SIZE, Output::message(Default::default(), 0)),
+    ];
+
+    for (size, mut output) in outputs {
+        assert_eq!(size, output.serialized_size());
+        assert_eq!(size, output.to_bytes().len());
+    }
+}
+
 #[test]
 fn iow_offset() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -90,6 +90,68 @@ fn input_coin_message_signature() {
     test(txs);
 }
 
+#[test]
+fn check_signature_detailed() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let txhash: Bytes32 = rng.gen();
+
+    let secret = SecretKey::random(rng);
+    let public = secret.public_key();
+    let message = unsafe { fuel_crypto::Message::from_bytes_unchecked(*txhash) };
+    let witnesses: Vec<Witness> = vec![fuel_crypto::Signature::sign(&secret, &message)
+        .as_ref()
+        .into()];
+
+    let outcome = Input::coin_signed(
+        rng.gen(),
+        Input::owner(&public),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        0,
+        rng.gen(),
+    )
+    .check_signature_detailed(0, &txhash, &witnesses)
+    .expect("Failed to validate signature");
+    assert_eq!(SignatureCheck::Verified, outcome);
+
+    let err = Input::coin_signed(
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        0,
+        rng.gen(),
+    )
+    .check_signature_detailed(0, &txhash, &witnesses)
+    .expect_err("Expected invalid signature");
+    assert_eq!(CheckError::InputInvalidSignature { index: 0 }, err);
+
+    let predicate = generate_nonempty_padded_bytes(rng);
+    let owner = Input::predicate_owner(&predicate);
+
+    let outcome = Input::message_predicate(
+        rng.gen(),
+        rng.gen(),
+        owner,
+        rng.gen(),
+        rng.gen(),
+        generate_bytes(rng),
+        predicate,
+        generate_bytes(rng),
+    )
+    .check_signature_detailed(1, &txhash, &[])
+    .expect("Failed to validate predicate owner");
+    assert_eq!(SignatureCheck::PredicateOwnerValid, outcome);
+
+    let outcome = Input::contract(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen())
+        .check_signature_detailed(2, &txhash, &[])
+        .expect("Contract inputs have no signature");
+    assert_eq!(SignatureCheck::NotApplicable, outcome);
+}
+
 #[test]
 fn coin_signed() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -54,7 +54,7 @@ fn contract() {
         .err()
         .unwrap();
 
-    assert_eq!(CheckError::OutputContractInputIndex { index: 2 }, err);
+    assert_eq!(CheckError::OutputContractInputNotContract { index: 2 }, err);
 
     let err = Output::contract(2, rng.gen(), rng.gen())
         .check(
@@ -78,6 +78,42 @@ fn contract() {
     assert_eq!(CheckError::OutputContractInputIndex { index: 2 }, err);
 }
 
+#[test]
+fn try_contract() {
+    let mut rng_base = StdRng::seed_from_u64(8586);
+    let rng = &mut rng_base;
+
+    let inputs = [
+        Input::coin_signed(
+            rng.gen(),
+            rng.gen(),
+            rng.next_u64(),
+            rng.gen(),
+            rng.gen(),
+            rng.next_u32().to_be_bytes()[0],
+            rng.next_u64(),
+        ),
+        Input::contract(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen()),
+    ];
+
+    let balance_root = rng.gen();
+    let state_root = rng.gen();
+
+    let output = Output::try_contract(1, &inputs, balance_root, state_root).unwrap();
+    assert_eq!(Output::contract(1, balance_root, state_root), output);
+    output.check(2, &inputs).unwrap();
+
+    let err = Output::try_contract(0, &inputs, balance_root, state_root)
+        .err()
+        .unwrap();
+    assert_eq!(CheckError::OutputContractInputNotContract { index: 0 }, err);
+
+    let err = Output::try_contract(2, &inputs, balance_root, state_root)
+        .err()
+        .unwrap();
+    assert_eq!(CheckError::OutputContractInputIndex { index: 2 }, err);
+}
+
 #[test]
 fn change() {
     let mut rng_base = StdRng::seed_from_u64(8586);
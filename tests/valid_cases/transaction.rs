@@ -74,6 +74,53 @@ fn gas_limit() {
     assert_eq!(CheckError::TransactionGasLimit, err);
 }
 
+#[test]
+fn gas_limit_below_intrinsic() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let maturity = 100;
+    let block_height = 1000;
+
+    let params = PARAMS.with_gas_costs(GasCosts {
+        signature_verification: 1_000,
+        input: 10,
+        output: 10,
+        ..GasCosts::DEFAULT
+    });
+
+    let secret = SecretKey::random(rng);
+    let asset_id: AssetId = rng.gen();
+
+    let mut script = |gas_limit| {
+        TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
+            .gas_limit(gas_limit)
+            .gas_price(rng.gen())
+            .maturity(maturity)
+            .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), asset_id, rng.gen(), rng.gen())
+            .add_output(Output::change(rng.gen(), rng.gen(), asset_id))
+            .finalize_as_transaction()
+    };
+
+    let tx = script(0);
+    let intrinsic = tx.intrinsic_gas(&params);
+
+    script(intrinsic)
+        .check(block_height, &params)
+        .expect("Failed to validate transaction");
+
+    let err = script(intrinsic - 1)
+        .check(block_height, &params)
+        .expect_err("Expected erroneous transaction");
+
+    assert_eq!(
+        CheckError::TransactionGasLimitBelowIntrinsic {
+            intrinsic,
+            limit: intrinsic - 1
+        },
+        err
+    );
+}
+
 #[test]
 fn maturity() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -139,6 +186,66 @@ fn maturity() {
     assert_eq!(CheckError::TransactionMaturity, err);
 }
 
+#[test]
+fn max_maturity() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let params = PARAMS.with_max_maturity(1000);
+    let block_height = 2000;
+
+    let mut script = |maturity| {
+        Transaction::script(
+            rng.gen(),
+            PARAMS.max_gas_per_tx,
+            maturity,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        )
+    };
+
+    script(params.max_maturity)
+        .check(block_height, &params)
+        .expect("Failed to validate script");
+
+    let err = script(params.max_maturity + 1)
+        .check(block_height, &params)
+        .expect_err("Expected erroneous transaction");
+
+    assert_eq!(CheckError::TransactionMaturityTooLarge, err);
+
+    let err = script(Word::MAX)
+        .check(block_height, &params)
+        .expect_err("Expected erroneous transaction");
+
+    assert_eq!(CheckError::TransactionMaturityTooLarge, err);
+
+    // The maximum maturity isn't enforced by default
+    let err = script(Word::MAX)
+        .check(block_height, &PARAMS)
+        .expect_err("Expected erroneous transaction");
+
+    assert_eq!(CheckError::TransactionMaturity, err);
+
+    let err = Transaction::create(
+        rng.gen(),
+        PARAMS.max_gas_per_tx,
+        params.max_maturity + 1,
+        0,
+        rng.gen(),
+        vec![],
+        vec![],
+        vec![],
+        vec![rng.gen()],
+    )
+    .check(block_height, &params)
+    .expect_err("Expected erroneous transaction");
+
+    assert_eq!(CheckError::TransactionMaturityTooLarge, err);
+}
+
 #[test]
 fn max_iow() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -411,6 +518,83 @@ fn script() {
         err
     );
 
+    TransactionBuilder::script(
+        vec![0xfa; PARAMS.max_script_length as usize],
+        vec![0xfb; PARAMS.max_script_data_length as usize],
+    )
+    .gas_limit(PARAMS.max_gas_per_tx)
+    .gas_price(rng.gen())
+    .maturity(maturity)
+    .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), asset_id, rng.gen(), rng.gen())
+    .add_output(Output::message(Address::zeroed(), 0))
+    .prepare_script(false)
+    .finalize()
+    .check(block_height, &PARAMS)
+    .expect("Failed to validate transaction");
+
+    let err = TransactionBuilder::script(
+        vec![0xfa; PARAMS.max_script_length as usize],
+        vec![0xfb; PARAMS.max_script_data_length as usize],
+    )
+    .gas_limit(PARAMS.max_gas_per_tx)
+    .gas_price(rng.gen())
+    .maturity(maturity)
+    .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), asset_id, rng.gen(), rng.gen())
+    .add_output(Output::change(rng.gen(), rng.gen(), asset_id))
+    .add_output(Output::message(rng.gen(), 0))
+    .prepare_script(false)
+    .finalize()
+    .check(block_height, &PARAMS)
+    .expect_err("Expected erroneous transaction");
+
+    assert_eq!(
+        CheckError::TransactionScriptOutputMessageNotZeroed { index: 1 },
+        err
+    );
+
+    let err = TransactionBuilder::script(
+        vec![0xfa; PARAMS.max_script_length as usize],
+        vec![0xfb; PARAMS.max_script_data_length as usize],
+    )
+    .gas_limit(PARAMS.max_gas_per_tx)
+    .gas_price(rng.gen())
+    .maturity(maturity)
+    .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), asset_id, rng.gen(), rng.gen())
+    .add_output(Output::message(Address::zeroed(), rng.gen()))
+    .prepare_script(false)
+    .finalize()
+    .check(block_height, &PARAMS)
+    .expect_err("Expected erroneous transaction");
+
+    assert_eq!(
+        CheckError::TransactionScriptOutputMessageNotZeroed { index: 0 },
+        err
+    );
+
+    let err = TransactionBuilder::script(
+        vec![0xfa; PARAMS.max_script_length as usize],
+        vec![0xfb; PARAMS.max_script_data_length as usize],
+    )
+    .gas_limit(PARAMS.max_gas_per_tx)
+    .gas_price(rng.gen())
+    .maturity(maturity)
+    .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), asset_id, rng.gen(), rng.gen())
+    .add_output(Output::Message {
+        recipient: Address::zeroed(),
+        amount: 0,
+        nonce: rng.gen(),
+        digest: Bytes32::zeroed(),
+    })
+    .prepare_script(false)
+    .finalize()
+    .check(block_height, &PARAMS)
+    .expect_err("Expected erroneous transaction");
+
+    assert_eq!(
+        CheckError::TransactionScriptOutputMessageNotZeroed { index: 0 },
+        err
+    );
+
     let err = TransactionBuilder::script(
         vec![0xfa; 1 + PARAMS.max_script_length as usize],
         vec![0xfb; PARAMS.max_script_data_length as usize],
@@ -440,6 +624,21 @@ fn script() {
     .expect_err("Expected erroneous transaction");
 
     assert_eq!(CheckError::TransactionScriptDataLength, err);
+
+    let err = TransactionBuilder::script(
+        vec![0xfa; PARAMS.max_script_length as usize],
+        vec![0xfb; PARAMS.max_script_data_length as usize],
+    )
+    .gas_limit(PARAMS.max_gas_per_tx)
+    .gas_price(rng.gen())
+    .maturity(maturity)
+    .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), asset_id, rng.gen(), rng.gen())
+    .add_output(Output::contract(0, rng.gen(), rng.gen()))
+    .finalize()
+    .check(block_height, &PARAMS)
+    .expect_err("Expected erroneous transaction");
+
+    assert_eq!(CheckError::OutputContractInputNotContract { index: 0 }, err);
 }
 
 #[test]
@@ -479,6 +678,27 @@ fn create() {
 
     assert_eq!(err, CheckError::TransactionCreateInputContract { index: 0 });
 
+    // Only transactions owing a fee need something to spend
+    let bytecode: Witness = generate_bytes(rng).into();
+    let salt = rng.gen();
+    let free = |gas_price| {
+        TransactionBuilder::create(bytecode.clone(), salt, vec![])
+            .gas_limit(PARAMS.max_gas_per_tx)
+            .gas_price(gas_price)
+            .maturity(maturity)
+            .finalize()
+    };
+
+    free(0)
+        .into_checked_basic(block_height, &PARAMS)
+        .expect("Failed to validate free transaction");
+
+    let err = free(1)
+        .into_checked_basic(block_height, &PARAMS)
+        .expect_err("Expected erroneous transaction");
+
+    assert_eq!(err, CheckError::TransactionCreateNoFeeInput);
+
     let err = TransactionBuilder::create(generate_bytes(rng).into(), rng.gen(), vec![])
         .gas_limit(PARAMS.max_gas_per_tx)
         .gas_price(rng.gen())
@@ -669,7 +889,7 @@ fn create() {
     let mut slot_data = [0u8; 64];
     let mut slot = StorageSlot::default();
 
-    let storage_slots = (0..PARAMS.max_storage_slots as u64)
+    let storage_slots = (0..PARAMS.max_storage_slots)
         .map(|i| {
             slot_data[..8].copy_from_slice(&i.to_be_bytes());
             let _ = slot.write(&slot_data).unwrap();
@@ -746,6 +966,63 @@ fn create() {
     assert_eq!(CheckError::TransactionCreateStorageSlotOrder, err);
 }
 
+#[test]
+fn create_change_set_base_asset() {
+    use fuel_tx::field::Outputs;
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let maturity = 100;
+    let block_height = 1000;
+
+    let secret = SecretKey::random(rng);
+    let asset_id: AssetId = rng.gen();
+
+    let mut tx = TransactionBuilder::create(generate_bytes(rng).into(), rng.gen(), vec![])
+        .gas_limit(PARAMS.max_gas_per_tx)
+        .gas_price(1)
+        .maturity(maturity)
+        .add_unsigned_coin_input(
+            secret,
+            rng.gen(),
+            Word::MAX,
+            PARAMS.base_asset_id,
+            rng.gen(),
+            maturity,
+        )
+        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), asset_id, rng.gen(), maturity)
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .finalize();
+
+    let err = tx
+        .clone()
+        .check(block_height, &PARAMS)
+        .expect_err("Expected erroneous transaction");
+    assert_eq!(
+        CheckError::TransactionCreateOutputChangeNotBaseAsset { index: 0 },
+        err
+    );
+
+    tx.outputs_mut()[0]
+        .set_base_asset()
+        .expect("Failed to set the base asset");
+    assert_eq!(Some(&AssetId::BASE), tx.outputs()[0].asset_id());
+
+    tx.check(block_height, &PARAMS)
+        .expect("Failed to validate tx");
+
+    let base_asset_id: AssetId = rng.gen();
+    let mut output = Output::coin(rng.gen(), 0, asset_id);
+    output
+        .set_base_asset_with_base(&base_asset_id)
+        .expect("Failed to set the base asset");
+    assert_eq!(Some(&base_asset_id), output.asset_id());
+
+    let mut output = Output::message(rng.gen(), 0);
+    assert_eq!(Err(()), output.set_base_asset());
+    assert_eq!(None, output.asset_id());
+}
+
 #[test]
 fn mint() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -789,6 +1066,99 @@ fn mint() {
     assert_eq!(err, CheckError::TransactionMintIncorrectBlockHeight);
 }
 
+#[test]
+fn mint_zero_coin_output() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let block_height = 1000;
+
+    let err = TransactionBuilder::mint(block_height, rng.gen())
+        .add_output(Output::coin(rng.gen(), rng.next_u64(), rng.gen()))
+        .add_output(Output::coin(rng.gen(), 0, rng.gen()))
+        .finalize()
+        .check(block_height as Word, &PARAMS)
+        .expect_err("Expected erroneous transaction");
+
+    assert_eq!(err, CheckError::TransactionMintZeroCoinOutput { index: 1 });
+}
+
+#[test]
+fn mint_from_fees() {
+    use fuel_tx::field::Outputs;
+    use std::collections::BTreeMap;
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let block_height = 1000;
+    let to: Address = rng.gen();
+    let (a, b): (AssetId, AssetId) = (rng.gen(), rng.gen());
+
+    let fees: BTreeMap<AssetId, Word> = [(a, 10), (b, 20), (rng.gen(), 0)].into_iter().collect();
+    let mint = Mint::from_fees(TxPointer::new(block_height, 0), to, &fees);
+
+    let mut expected = vec![Output::coin(to, 10, a), Output::coin(to, 20, b)];
+    expected.sort_by_key(|output| *output.asset_id().unwrap());
+    assert_eq!(&expected, mint.outputs());
+
+    mint.check(block_height as Word, &PARAMS)
+        .expect("Failed to validate tx");
+}
+
+#[test]
+fn mint_exceeds_cap() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let block_height = 1000;
+    let cap = 1_000_000;
+    let asset_id: AssetId = rng.gen();
+
+    let mut mint = |amount| {
+        TransactionBuilder::mint(block_height, rng.gen())
+            .add_output(Output::coin(rng.gen(), 1, rng.gen()))
+            .add_output(Output::coin(rng.gen(), amount, asset_id))
+            .finalize()
+    };
+
+    mint(cap)
+        .check_mint_cap(cap)
+        .expect("Failed to validate mint cap");
+
+    let err = mint(cap + 1)
+        .check_mint_cap(cap)
+        .expect_err("Expected erroneous transaction");
+
+    assert_eq!(
+        err,
+        CheckError::TransactionMintExceedsCap { asset: asset_id }
+    );
+}
+
+#[test]
+fn mint_tx_pointer_block_height() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let block_height = 1000;
+    let tx = TransactionBuilder::mint(block_height, rng.gen())
+        .add_output(Output::coin(rng.gen(), rng.next_u64(), rng.gen()))
+        .finalize();
+
+    assert_eq!(
+        block_height,
+        field::TxPointer::tx_pointer(&tx).block_height()
+    );
+
+    tx.check(block_height as Word, &PARAMS)
+        .expect("Failed to validate tx");
+
+    for height in [0, block_height as Word - 1, block_height as Word + 1] {
+        let err = tx
+            .check(height, &PARAMS)
+            .expect_err("Expected erroneous transaction");
+
+        assert_eq!(err, CheckError::TransactionMintIncorrectBlockHeight);
+    }
+}
+
 #[test]
 fn tx_id_bytecode_len() {
     let rng = &mut StdRng::seed_from_u64(8586);